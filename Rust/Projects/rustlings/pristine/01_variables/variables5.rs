@@ -0,0 +1,8 @@
+fn main() {
+    let number = "T-H-R-E-E"; // Don't change this line
+    println!("Spell a number: {}", number);
+
+    // TODO: Fix the compiler error by changing the line below without renaming the variable.
+    number = 3;
+    println!("Number plus two is: {}", number + 2);
+}
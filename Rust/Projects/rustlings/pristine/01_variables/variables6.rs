@@ -0,0 +1,6 @@
+// TODO: Change the line below to fix the compiler error.
+const NUMBER = 3;
+
+fn main() {
+    println!("Number: {NUMBER}");
+}
@@ -0,0 +1,8 @@
+fn main() {
+    let cat = ("Furry McFurson", 3.5);
+
+    // TODO: Destructure the `cat` tuple in one statement so that the println works.
+    // let /* your pattern here */ = cat;
+
+    println!("{name} is {age} years old");
+}
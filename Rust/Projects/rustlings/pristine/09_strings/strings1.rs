@@ -0,0 +1,9 @@
+// TODO: Fix the compiler error without changing the function signature.
+fn current_favorite_color() -> String {
+    "blue"
+}
+
+fn main() {
+    let answer = current_favorite_color();
+    println!("My current favorite color is {answer}");
+}
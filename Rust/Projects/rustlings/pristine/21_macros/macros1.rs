@@ -0,0 +1,10 @@
+macro_rules! my_macro {
+    () => {
+        println!("Check out my macro!");
+    };
+}
+
+fn main() {
+    // TODO: Fix the macro call.
+    my_macro();
+}
@@ -0,0 +1,13 @@
+// TODO: Fix the compiler error without taking the macro definition out of this
+// module.
+mod macros {
+    macro_rules! my_macro {
+        () => {
+            println!("Check out my macro!");
+        };
+    }
+}
+
+fn main() {
+    my_macro!();
+}
@@ -0,0 +1,35 @@
+// Copies the code of the conversion exercises into `OUT_DIR` without their
+// test modules, so that only the property tests run.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+const EXERCISES: &[&str] = &["from_into", "from_str", "try_from_into"];
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=RUSTLINGS_EXERCISES");
+    let dir = env::var_os("RUSTLINGS_EXERCISES")
+        .map_or_else(|| PathBuf::from("../rustlings/exercises"), PathBuf::from);
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+
+    for name in EXERCISES {
+        let path = dir.join("23_conversions").join(format!("{name}.rs"));
+        println!("cargo:rerun-if-changed={}", path.display());
+        let source = fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("Failed to read {}: {err}", path.display()));
+
+        // The test module is the last item of every exercise, and `include!`
+        // can't take inner attributes like `#![allow(clippy::useless_vec)]`,
+        // so the module that includes the code repeats them.
+        let code: String = source
+            .lines()
+            .take_while(|line| *line != "#[cfg(test)]")
+            .filter(|line| !line.starts_with("#!["))
+            .map(|line| format!("{line}\n"))
+            .collect();
+        let out = out_dir.join(format!("{name}.rs"));
+        fs::write(&out, code)
+            .unwrap_or_else(|err| panic!("Failed to write {}: {err}", out.display()));
+    }
+}
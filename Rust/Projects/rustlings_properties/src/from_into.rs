@@ -0,0 +1,44 @@
+// `main` isn't used.
+#![allow(dead_code)]
+
+use proptest::prelude::*;
+
+include!(concat!(env!("OUT_DIR"), "/from_into.rs"));
+
+// For `from_str`, which should accept the same inputs.
+pub fn person(s: &str) -> (String, u8) {
+    let person = Person::from(s);
+    (person.name, person.age)
+}
+
+// The `Person` that `From` should build for an input, or `None` for the
+// default.
+fn expected(s: &str) -> Option<(String, u8)> {
+    let (name, age) = s.split_once(',')?;
+    if name.is_empty() || age.contains(',') {
+        return None;
+    }
+    Some((name.to_string(), age.parse().ok()?))
+}
+
+proptest! {
+    #![proptest_config(crate::config())]
+
+    #[test]
+    fn never_panics(s in "\\PC*") {
+        let _ = Person::from(s.as_str());
+    }
+
+    #[test]
+    fn parses_valid_inputs(name in "[^,]+", age: u8) {
+        let p = Person::from(format!("{name},{age}").as_str());
+        prop_assert_eq!(p.name, name);
+        prop_assert_eq!(p.age, age);
+    }
+
+    #[test]
+    fn falls_back_to_the_default(s in "[a-zA-Z]{0,6}(,[0-9a-z+-]{0,4}){0,3}") {
+        let (name, age) = expected(&s).unwrap_or((String::from("John"), 30));
+        prop_assert_eq!(person(&s), (name, age));
+    }
+}
@@ -0,0 +1,37 @@
+// `main` isn't used.
+#![allow(dead_code)]
+
+use proptest::prelude::*;
+
+include!(concat!(env!("OUT_DIR"), "/from_str.rs"));
+
+use ParsePersonError::*;
+
+proptest! {
+    #![proptest_config(crate::config())]
+
+    #[test]
+    fn never_panics(s in "\\PC*") {
+        let _ = s.parse::<Person>();
+    }
+
+    #[test]
+    fn parses_valid_inputs(name in "[^,]+", age: u8) {
+        let p = format!("{name},{age}").parse::<Person>();
+        prop_assert_eq!(p, Ok(Person { name, age }));
+    }
+
+    // Checked against the learner's `From` of the previous exercise.
+    #[test]
+    fn agrees_with_from(s in "[a-zA-Z]{0,6}(,[0-9a-z+-]{0,4}){0,3}") {
+        let (name, age) = crate::from_into::person(&s);
+        match s.parse::<Person>() {
+            Ok(p) => prop_assert_eq!((p.name, p.age), (name, age)),
+            // `From` can only tell the default apart by its value.
+            Err(_) => prop_assert_eq!((name.as_str(), age), ("John", 30)),
+        }
+        if s.matches(',').count() != 1 {
+            prop_assert_eq!(s.parse::<Person>(), Err(BadLen));
+        }
+    }
+}
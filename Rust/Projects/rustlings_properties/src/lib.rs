@@ -0,0 +1,31 @@
+/*
+Property tests for the learner's solutions of `from_into`, `from_str` and
+`try_from_into` in `23_conversions`, on top of the hand-picked examples in
+their test modules:
+
+    cargo test --manifest-path Projects/rustlings_properties/Cargo.toml
+
+They live in their own crate so that the exercises crate has no dependencies
+to download, and the crate is left out of the workspace because it only
+compiles once the three exercises are solved. `build.rs` copies the code of
+each exercise without its test module, so only the properties below run.
+`RUSTLINGS_EXERCISES` points it at another `exercises` directory, relative to
+this crate.
+*/
+
+#[cfg(test)]
+mod from_into;
+#[cfg(test)]
+mod from_str;
+#[cfg(test)]
+mod try_from_into;
+
+#[cfg(test)]
+fn config() -> proptest::prelude::ProptestConfig {
+    proptest::prelude::ProptestConfig {
+        // Failing inputs depend on the solution at hand, so they aren't saved
+        // next to the tests.
+        failure_persistence: None,
+        ..Default::default()
+    }
+}
@@ -0,0 +1,45 @@
+// `main` isn't used, and the exercise allows its `vec!`.
+#![allow(dead_code, clippy::useless_vec)]
+
+use proptest::prelude::*;
+
+include!(concat!(env!("OUT_DIR"), "/try_from_into.rs"));
+
+use IntoColorError::*;
+
+fn in_range(component: i16) -> bool {
+    (0..=255).contains(&component)
+}
+
+proptest! {
+    #![proptest_config(crate::config())]
+
+    #[test]
+    fn accepts_exactly_the_components_in_range(tuple: (i16, i16, i16)) {
+        let (red, green, blue) = tuple;
+        let expected = if in_range(red) && in_range(green) && in_range(blue) {
+            Ok(Color {
+                red: red as u8,
+                green: green as u8,
+                blue: blue as u8,
+            })
+        } else {
+            Err(IntConversion)
+        };
+        prop_assert_eq!(Color::try_from(tuple), expected);
+    }
+
+    #[test]
+    fn agrees_for_tuples_arrays_and_slices(tuple in (-300..300i16, -300..300i16, -300..300i16)) {
+        let array = [tuple.0, tuple.1, tuple.2];
+        prop_assert_eq!(Color::try_from(array), Color::try_from(tuple));
+        prop_assert_eq!(Color::try_from(&array[..]), Color::try_from(tuple));
+    }
+
+    #[test]
+    fn checks_the_slice_length(slice in prop::collection::vec(0..=255i16, 0..8)) {
+        if slice.len() != 3 {
+            prop_assert_eq!(Color::try_from(&slice[..]), Err(BadLen));
+        }
+    }
+}
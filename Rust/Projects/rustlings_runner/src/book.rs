@@ -0,0 +1,110 @@
+/*
+The book chapter table in `exercises/README.md`.
+
+Some compiler errors come up again and again in the exercises, and each of
+them belongs to one topic of the table. When one of them shows up, the
+diagnostic points at the row of that topic, e.g.
+
+    = book: use after move is covered by `move_semantics` in §4.1-2, see exercises/README.md:10
+*/
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+
+const README: &str = "exercises/README.md";
+
+// Error codes, what they mean in a few words and the topic explaining them.
+const ERROR_TOPICS: &[(&str, &str, &str)] = &[
+    ("E0382", "use after move", "move_semantics"),
+    ("E0505", "moving a borrowed value", "move_semantics"),
+    ("E0499", "two mutable borrows", "move_semantics"),
+    ("E0502", "conflicting borrows", "move_semantics"),
+    ("E0384", "assigning twice", "variables"),
+    ("E0106", "a missing lifetime", "lifetimes"),
+    ("E0597", "a value dropped too early", "lifetimes"),
+    ("E0599", "a method that isn't implemented", "traits"),
+    ("E0277", "a trait that isn't implemented", "traits"),
+];
+
+struct Row {
+    topic: String,
+    chapter: String,
+    // 1-based, so `README:line` can be opened from the terminal.
+    line: usize,
+}
+
+#[derive(Default)]
+pub struct Book {
+    rows: Vec<Row>,
+}
+
+impl Book {
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join(README);
+        let readme = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Ok(Self::parse(&readme))
+    }
+
+    // Reads the `| topic | chapter |` rows, skipping the header and the
+    // separator.
+    fn parse(readme: &str) -> Self {
+        let rows = readme
+            .lines()
+            .enumerate()
+            .filter_map(|(n, line)| {
+                let cells: Vec<&str> = line.trim().strip_prefix('|')?.split('|').collect();
+                let (topic, chapter) = (cells.first()?.trim(), cells.get(1)?.trim());
+                if topic == "Exercise" || topic.starts_with('-') {
+                    return None;
+                }
+                Some(Row {
+                    topic: topic.to_string(),
+                    chapter: chapter.to_string(),
+                    line: n + 1,
+                })
+            })
+            .collect();
+        Self { rows }
+    }
+
+    // The chapter of a topic, like `§4.1-2` for `move_semantics`.
+    pub fn chapter(&self, topic: &str) -> Option<&str> {
+        let row = self.rows.iter().find(|row| row.topic == topic)?;
+        Some(&row.chapter)
+    }
+
+    // Points an error code at the topic that explains it, if it's one of the
+    // well-known ones.
+    pub fn note(&self, code: &str) -> Option<String> {
+        let &(_, meaning, topic) = ERROR_TOPICS.iter().find(|(known, ..)| *known == code)?;
+        let row = self.rows.iter().find(|row| row.topic == topic)?;
+        Some(format!(
+            "{meaning} is covered by `{topic}` in {}, see {README}:{}",
+            row.chapter, row.line
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::ExerciseList;
+
+    #[test]
+    fn links_error_codes_to_the_chapter_table() {
+        let book = Book::load(&ExerciseList::checked_in().root).unwrap();
+
+        assert_eq!(
+            book.note("E0382").unwrap(),
+            "use after move is covered by `move_semantics` in §4.1-2, see exercises/README.md:10"
+        );
+        assert_eq!(
+            book.note("E0106").unwrap(),
+            "a missing lifetime is covered by `lifetimes` in §10.3, see exercises/README.md:20"
+        );
+        assert!(book.note("E0599").is_some());
+        assert_eq!(book.note("E0308"), None);
+    }
+}
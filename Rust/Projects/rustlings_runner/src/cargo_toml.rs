@@ -0,0 +1,93 @@
+/*
+Generates the `bin` list of the exercises crate's `Cargo.toml` from
+`info.toml`, so the two can't drift apart. Everything from `[package]` on is
+kept as it is.
+
+Exercise packs may come without a `Cargo.toml`, in which case a new one is
+written.
+*/
+
+use anyhow::{bail, Context, Result};
+use std::fs;
+
+use crate::exercise::{Exercise, ExerciseList};
+
+const PACKAGE_TABLE: &str = "[package]";
+// A pack is its own workspace, so it builds wherever it's stored, even inside
+// another workspace.
+const NEW_PACKAGE: &str = "[package]
+name = \"exercises\"
+edition = \"2021\"
+# Don't publish the exercises on crates.io!
+publish = false
+
+[workspace]
+";
+
+pub fn render(exercises: &[Exercise], current: &str) -> Result<String> {
+    let package = match current.find(PACKAGE_TABLE) {
+        Some(start) => &current[start..],
+        None => bail!("Cargo.toml has no `{PACKAGE_TABLE}` table"),
+    };
+
+    let mut contents = String::from("bin = [\n");
+    for exercise in exercises {
+        bin_entry(&mut contents, &exercise.name, &exercise.path.display());
+        bin_entry(
+            &mut contents,
+            &exercise.solution_bin(),
+            &exercise.solution.display(),
+        );
+    }
+    contents.push_str("]\n\n");
+    contents.push_str(package);
+    Ok(contents)
+}
+
+fn bin_entry(contents: &mut String, name: &str, path: &impl std::fmt::Display) {
+    contents.push_str(&format!("  {{ name = \"{name}\", path = \"{path}\" }},\n"));
+}
+
+// Returns whether `Cargo.toml` was already up to date. With `check`, only
+// compares and never writes.
+pub fn update(list: &ExerciseList, check: bool) -> Result<bool> {
+    let path = list.root.join("Cargo.toml");
+    let current = if path.exists() {
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?
+    } else {
+        String::new()
+    };
+    let package = if current.is_empty() {
+        NEW_PACKAGE
+    } else {
+        &current
+    };
+    let generated = render(&list.exercises, package)?;
+
+    if generated == current {
+        return Ok(true);
+    }
+    if !check {
+        fs::write(&path, generated)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Fails when someone edits one of the two files without the other.
+    #[test]
+    fn checked_in_cargo_toml_matches_info_toml() {
+        let list = ExerciseList::checked_in();
+        let root = &list.root;
+        let current = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+
+        assert!(
+            render(&list.exercises, &current).unwrap() == current,
+            "Cargo.toml is out of date, run `cargo run -p rustlings_runner -- cargo-toml`"
+        );
+    }
+}
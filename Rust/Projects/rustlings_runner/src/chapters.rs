@@ -0,0 +1,283 @@
+/*
+Links exercises to our own chapter crates in `Rust Programming Language Book`,
+the notes and experiments written while reading the book.
+
+An exercise's topic has a row in the chapter table of `exercises/README.md`,
+like `structs | §5.1, §5.3`, and the chapter crates are named after their
+chapter, like `chapter 5 structs`. So `structs1` points at
+`chapter 5 structs/src/main.rs`. Exercises whose topic has no chapter in the
+table, like `conversions`, fall back to the `book` field in `info.toml`.
+
+When several crates belong to the chapter (chapter 3 has three), the one whose
+title matches the topic comes first and is the one that is run. Not every
+chapter crate is a member of the workspace, and cargo refuses to build the
+others from inside it, so they are run with a manifest of their own in a
+scratch directory.
+*/
+
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
+
+use crate::book::Book;
+use crate::crosscheck::WorkDir;
+use crate::exercise::{Exercise, ExerciseList};
+use crate::verify::cargo;
+
+// Next to `Projects/rustlings`.
+const BOOK_DIR: &str = "../Rust Programming Language Book";
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ChapterCrate {
+    pub chapter: u32,
+    // The rest of the directory name, like `control flow`.
+    pub title: String,
+    pub dir: PathBuf,
+}
+
+impl ChapterCrate {
+    pub fn notes(&self) -> PathBuf {
+        self.dir.join("src").join("main.rs")
+    }
+}
+
+// The chapters of a list of sections, like 6 and 18 for `§6, §18.3`.
+fn chapter_numbers(sections: &str) -> Vec<u32> {
+    let mut chapters = Vec::new();
+    for section in sections.split(',') {
+        let digits: String = section
+            .trim()
+            .trim_start_matches('§')
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .collect();
+        if let Ok(chapter) = digits.parse() {
+            if !chapters.contains(&chapter) {
+                chapters.push(chapter);
+            }
+        }
+    }
+    chapters
+}
+
+// Every `chapter <N> <title>` crate, ordered by chapter and title.
+fn crates(book_dir: &Path) -> Result<Vec<ChapterCrate>> {
+    let mut crates = Vec::new();
+    if !book_dir.is_dir() {
+        return Ok(crates);
+    }
+    let entries =
+        fs::read_dir(book_dir).with_context(|| format!("Failed to read {}", book_dir.display()))?;
+    for entry in entries {
+        let entry = entry.with_context(|| format!("Failed to read {}", book_dir.display()))?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let Some((chapter, title)) = name
+            .strip_prefix("chapter ")
+            .and_then(|rest| rest.split_once(' '))
+        else {
+            continue;
+        };
+        let (Ok(chapter), true) = (chapter.parse(), entry.path().join("Cargo.toml").is_file())
+        else {
+            continue;
+        };
+        crates.push(ChapterCrate {
+            chapter,
+            title: title.to_string(),
+            dir: entry.path(),
+        });
+    }
+    crates.sort_by(|a, b| (a.chapter, &a.title).cmp(&(b.chapter, &b.title)));
+    Ok(crates)
+}
+
+// The sections of the book the exercise is about.
+fn book_sections(book: &Book, exercise: &Exercise) -> Option<String> {
+    let topic = exercise
+        .dir
+        .split_once('_')
+        .map_or(exercise.dir.as_str(), |(_, topic)| topic);
+    book.chapter(topic)
+        .filter(|chapter| !chapter_numbers(chapter).is_empty())
+        .map(str::to_string)
+        .or_else(|| exercise.book.clone())
+}
+
+// The chapter crates for the exercise, the one matching its topic first.
+fn matching(crates: Vec<ChapterCrate>, exercise: &Exercise, sections: &str) -> Vec<ChapterCrate> {
+    let chapters = chapter_numbers(sections);
+    let topic = exercise.dir.replace('_', " ");
+    let mut matching: Vec<ChapterCrate> = crates
+        .into_iter()
+        .filter(|found| chapters.contains(&found.chapter))
+        .collect();
+    // Stable, so the chapters stay in order otherwise.
+    matching.sort_by_key(|found| !topic.ends_with(&found.title));
+    matching
+}
+
+fn lookup(list: &ExerciseList, name: &str) -> Result<(String, Vec<ChapterCrate>)> {
+    let exercise = list.find(name)?;
+    let book = Book::load(&list.root).unwrap_or_default();
+    let Some(sections) = book_sections(&book, exercise) else {
+        bail!("`{name}` isn't linked to a chapter of the book");
+    };
+    let book_dir = list.root.join(BOOK_DIR);
+    let crates = crates(&book_dir.canonicalize().unwrap_or(book_dir))?;
+    let matching = matching(crates, exercise, &sections);
+    Ok((sections, matching))
+}
+
+// Prints where the book and our chapter crates cover the exercise.
+pub fn show(list: &ExerciseList, name: &str) -> Result<()> {
+    let (sections, crates) = lookup(list, name)?;
+    println!("`{name}` is covered by {sections} of the book.");
+    if crates.is_empty() {
+        println!("There are no chapter crates for it yet.");
+    }
+    for found in &crates {
+        println!(
+            "Chapter {} notes: {}",
+            found.chapter,
+            found.notes().display()
+        );
+    }
+    Ok(())
+}
+
+// The chapter crate's manifest as a workspace of its own, building the
+// crate's `src/main.rs` from wherever the manifest is written.
+fn standalone_manifest(manifest: &str, main: &Path) -> Result<String> {
+    let mut table: toml::Table = toml::from_str(manifest)?;
+    let name = table
+        .get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(|name| name.as_str())
+        .context("The manifest has no package name")?
+        .to_string();
+    let mut bin = toml::Table::new();
+    bin.insert("name".to_string(), name.into());
+    bin.insert("path".to_string(), main.display().to_string().into());
+    table.insert("bin".to_string(), vec![toml::Value::Table(bin)].into());
+    table
+        .entry("workspace")
+        .or_insert_with(|| toml::Table::new().into());
+    Ok(toml::to_string(&table)?)
+}
+
+// Runs the chapter crate of the exercise, with the terminal attached for
+// programs like the guessing game.
+pub fn run(list: &ExerciseList, name: &str) -> Result<ExitStatus> {
+    let (sections, crates) = lookup(list, name)?;
+    let Some(found) = crates.first() else {
+        bail!("There is no chapter crate for {sections}, which `{name}` is about");
+    };
+    let path = found.dir.join("Cargo.toml");
+    let manifest =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let manifest = standalone_manifest(&manifest, &found.notes())
+        .with_context(|| format!("Invalid {}", path.display()))?;
+    let work_dir = WorkDir::new()?;
+    let path = work_dir.path().join("Cargo.toml");
+    fs::write(&path, manifest).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    println!("Running chapter {} ({})\n", found.chapter, found.title);
+    let mut cargo = cargo();
+    cargo
+        .args(["run", "--quiet", "--manifest-path"])
+        .arg(&path)
+        // Next to the crate, so it isn't built from scratch every time.
+        .arg("--target-dir")
+        .arg(found.dir.join("target"))
+        // Where `cargo run` in the crate would run it.
+        .current_dir(&found.dir);
+    cargo
+        .status()
+        .with_context(|| format!("Failed to run {cargo:?}"))
+}
+
+// The notes for the exercise, if there are any, for pointing at them from
+// other commands.
+pub fn notes(list: &ExerciseList, exercise: &Exercise) -> Option<PathBuf> {
+    let (_, crates) = lookup(list, &exercise.name).ok()?;
+    crates.first().map(ChapterCrate::notes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::Mode;
+
+    #[test]
+    fn reads_chapters_from_sections() {
+        assert_eq!(chapter_numbers("§5.1, §5.3"), [5]);
+        assert_eq!(chapter_numbers("§6, §18.3"), [6, 18]);
+        assert_eq!(chapter_numbers("§4.1-2"), [4]);
+        assert_eq!(chapter_numbers("n/a"), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn makes_the_manifest_a_workspace_of_its_own() {
+        let manifest = "[package]\nname = \"structs\"\nedition = \"2021\"\n\n[dependencies]\n";
+        let standalone: toml::Table =
+            toml::from_str(&standalone_manifest(manifest, Path::new("/book/main.rs")).unwrap())
+                .unwrap();
+
+        assert_eq!(standalone["package"]["edition"].as_str(), Some("2021"));
+        assert_eq!(standalone["bin"][0]["name"].as_str(), Some("structs"));
+        assert_eq!(standalone["bin"][0]["path"].as_str(), Some("/book/main.rs"));
+        assert!(standalone["workspace"].is_table());
+        assert!(standalone_manifest("[dependencies]\n", Path::new("main.rs")).is_err());
+    }
+
+    #[test]
+    fn falls_back_to_the_book_field_of_the_exercise() {
+        let list = ExerciseList::checked_in();
+        let book = Book::load(&list.root).unwrap();
+        let mut using_as = Exercise::for_test("23_conversions", "using_as", Mode::Run);
+        let mut structs1 = Exercise::for_test("07_structs", "structs1", Mode::Run);
+        assert_eq!(book_sections(&book, &using_as), None);
+
+        using_as.book = Some("§3.2".to_string());
+        structs1.book = Some("§3.2".to_string());
+        assert_eq!(book_sections(&book, &using_as).as_deref(), Some("§3.2"));
+        // The table of the exercises' README comes first.
+        assert_eq!(
+            book_sections(&book, &structs1).as_deref(),
+            Some("§5.1, §5.3")
+        );
+    }
+
+    #[test]
+    fn links_exercises_to_the_checked_in_chapter_crates() {
+        let list = ExerciseList::checked_in();
+        let title = |name: &str| {
+            let (_, crates) = lookup(&list, name).unwrap();
+            crates
+                .iter()
+                .map(|found| format!("chapter {} {}", found.chapter, found.title))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(title("structs1"), ["chapter 5 structs"]);
+        assert_eq!(title("move_semantics1"), ["chapter 4 ownership"]);
+        assert_eq!(
+            title("functions1"),
+            [
+                "chapter 3 functions",
+                "chapter 3 control flow",
+                "chapter 3 variables"
+            ]
+        );
+        assert_eq!(title("iterators1"), Vec::<String>::new());
+        // `conversions` is "n/a" in the table and `using_as` has no `book`.
+        assert_eq!(
+            lookup(&list, "using_as").unwrap_err().to_string(),
+            "`using_as` isn't linked to a chapter of the book"
+        );
+        assert!(notes(&list, list.find("structs1").unwrap())
+            .unwrap()
+            .ends_with("chapter 5 structs/src/main.rs"));
+    }
+}
@@ -0,0 +1,229 @@
+/*
+Checks the reference solutions against the exercises' tests.
+
+The `_sol` bins only run their own copy of the tests, which can drift from the
+tests in `exercises/`. Here the test module of the exercise file is appended to
+the body of the solution file and the result is compiled with `rustc --test`.
+Run-mode exercises have no tests, so their solution only has to compile and
+exit successfully.
+
+The test module is expected to be the last item of the file, starting at its
+`#[cfg(test)]` line, which is how every exercise is laid out.
+*/
+
+use anyhow::{Context, Result};
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use crate::exercise::{Exercise, ExerciseList, Mode};
+use crate::process;
+use crate::solution::PLACEHOLDER;
+use crate::verify::DEFAULT_TIMEOUT;
+
+const TEST_MODULE: &str = "#[cfg(test)]";
+const COMPILE_TIMEOUT: Duration = Duration::from_secs(60);
+
+pub enum CrossCheck {
+    Passed,
+    // The upstream tool hasn't filled in the solution file yet.
+    NoSolution,
+    Failed(String),
+}
+
+// Splits a source file into everything before its test module and the test
+// module itself.
+pub fn split_tests(source: &str) -> (&str, Option<&str>) {
+    let start = source
+        .match_indices(TEST_MODULE)
+        .map(|(start, _)| start)
+        .find(|&start| start == 0 || source[..start].ends_with('\n'));
+
+    match start {
+        Some(start) => (&source[..start], Some(&source[start..])),
+        None => (source, None),
+    }
+}
+
+pub fn rustc() -> Command {
+    Command::new(std::env::var_os("RUSTC").unwrap_or_else(|| OsString::from("rustc")))
+}
+
+// A solution that hangs fails like one whose tests fail.
+fn run(command: &mut Command, timeout: Duration, output: &mut String) -> Result<bool> {
+    let run = process::run(command, timeout)?;
+    output.push_str(&run.stderr);
+    output.push_str(&run.stdout);
+    if run.timed_out() {
+        output.push_str(&format!("Stopped after {}s\n", timeout.as_secs()));
+    }
+    Ok(run.success())
+}
+
+// Compiles `source` into `work_dir` and runs the result before the exercise's
+// timeout.
+fn compile_and_run(
+    source: &str,
+    test: bool,
+    exercise: &Exercise,
+    work_dir: &Path,
+) -> Result<CrossCheck> {
+    let name = exercise.name.as_str();
+    let source_path = work_dir.join(format!("{name}.rs"));
+    let binary = work_dir.join(name);
+    fs::write(&source_path, source)
+        .with_context(|| format!("Failed to write {}", source_path.display()))?;
+
+    let mut rustc = rustc();
+    rustc
+        .args(["--edition", "2021", "--crate-name", name, "-A", "warnings"])
+        .arg(&source_path)
+        .arg("-o")
+        .arg(&binary);
+    if test {
+        rustc.arg("--test");
+    }
+
+    let timeout = exercise.timeout.unwrap_or(DEFAULT_TIMEOUT);
+    let mut output = String::new();
+    if run(&mut rustc, COMPILE_TIMEOUT, &mut output)?
+        && run(&mut Command::new(&binary), timeout, &mut output)?
+    {
+        Ok(CrossCheck::Passed)
+    } else {
+        Ok(CrossCheck::Failed(output))
+    }
+}
+
+pub fn check(root: &Path, exercise: &Exercise, work_dir: &Path) -> Result<CrossCheck> {
+    let read = |path: &Path| {
+        fs::read_to_string(root.join(path))
+            .with_context(|| format!("Failed to read {}", path.display()))
+    };
+    let solution = read(&exercise.solution)?;
+    if solution.contains(PLACEHOLDER) {
+        return Ok(CrossCheck::NoSolution);
+    }
+
+    match exercise.mode {
+        Mode::Run => compile_and_run(&solution, false, exercise, work_dir),
+        Mode::Test => {
+            let source = read(&exercise.path)?;
+            let Some(tests) = split_tests(&source).1 else {
+                return Ok(CrossCheck::Failed(format!(
+                    "{} has no test module",
+                    exercise.path.display()
+                )));
+            };
+            let (body, _) = split_tests(&solution);
+            let combined = format!("{body}\n{tests}");
+            compile_and_run(&combined, true, exercise, work_dir)
+        }
+    }
+}
+
+// A scratch directory for the compiled files, removed when dropped.
+pub struct WorkDir(PathBuf);
+
+impl WorkDir {
+    pub fn new() -> Result<Self> {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let dir = std::env::temp_dir().join(format!(
+            "rustlings-crosscheck-{}-{}",
+            std::process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+        Ok(Self(dir))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for WorkDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+// Checks every exercise and prints the result. Returns the names of the
+// exercises whose solution failed.
+pub fn check_all(list: &ExerciseList) -> Result<Vec<String>> {
+    let work_dir = WorkDir::new()?;
+    let mut failed = Vec::new();
+
+    for exercise in &list.exercises {
+        match check(&list.root, exercise, work_dir.path())? {
+            CrossCheck::Passed => println!("✓ {}", exercise.name),
+            CrossCheck::NoSolution => println!("- {} (no reference solution yet)", exercise.name),
+            CrossCheck::Failed(output) => {
+                print!("{output}");
+                println!("✗ {}", exercise.name);
+                failed.push(exercise.name.clone());
+            }
+        }
+    }
+    Ok(failed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_off_the_test_module() {
+        let source = "fn main() {}\n\n// Don't change the tests!\n#[cfg(test)]\nmod tests {}\n";
+        let (body, tests) = split_tests(source);
+
+        assert_eq!(body, "fn main() {}\n\n// Don't change the tests!\n");
+        assert_eq!(tests, Some("#[cfg(test)]\nmod tests {}\n"));
+        assert_eq!(split_tests("fn main() {}\n"), ("fn main() {}\n", None));
+    }
+
+    #[test]
+    fn stops_solutions_that_hang() {
+        let root = WorkDir::new().unwrap();
+        let work_dir = WorkDir::new().unwrap();
+        let mut exercise = Exercise::for_test("20_threads", "threads3", Mode::Run);
+        exercise.timeout = Some(Duration::from_secs(1));
+        let solution = root.path().join(&exercise.solution);
+        fs::create_dir_all(solution.parent().unwrap()).unwrap();
+        fs::write(
+            &solution,
+            "fn main() {\n    loop {\n        std::thread::park();\n    }\n}\n",
+        )
+        .unwrap();
+
+        match check(root.path(), &exercise, work_dir.path()).unwrap() {
+            CrossCheck::Failed(output) => assert!(output.ends_with("Stopped after 1s\n")),
+            _ => panic!("A hanging solution must fail"),
+        }
+    }
+
+    // Fails when a reference solution no longer passes its exercise's tests.
+    #[test]
+    fn solutions_pass_the_exercise_tests() {
+        let list = ExerciseList::checked_in();
+        let root = &list.root;
+        let work_dir = WorkDir::new().unwrap();
+
+        let failed: Vec<&str> = list
+            .exercises
+            .iter()
+            .filter(|exercise| {
+                matches!(
+                    check(root, exercise, work_dir.path()).unwrap(),
+                    CrossCheck::Failed(_)
+                )
+            })
+            .map(|exercise| exercise.name.as_str())
+            .collect();
+
+        assert!(failed.is_empty(), "These solutions fail: {failed:?}");
+    }
+}
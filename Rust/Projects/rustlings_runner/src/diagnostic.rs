@@ -0,0 +1,262 @@
+/*
+The compiler diagnostics in cargo's `--message-format=json` output.
+
+Cargo prints one JSON object per line. The `compiler-message` lines are
+grouped by the bin they belong to, so the diagnostics of an exercise can be
+shown under its name, and the `compiler-artifact` lines tell where the built
+binaries are.
+*/
+
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::book::Book;
+use crate::exercise::Exercise;
+
+#[derive(Deserialize)]
+struct CargoMessage {
+    reason: String,
+    target: Option<Target>,
+    message: Option<Diagnostic>,
+    profile: Option<Profile>,
+    executable: Option<PathBuf>,
+}
+
+#[derive(Deserialize)]
+struct Target {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct Profile {
+    test: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Diagnostic {
+    pub level: String,
+    pub message: String,
+    pub code: Option<Code>,
+    pub spans: Vec<Span>,
+    // The notes and help messages below the diagnostic.
+    #[serde(default)]
+    pub children: Vec<Diagnostic>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Code {
+    pub code: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Span {
+    pub file_name: String,
+    pub line_start: usize,
+    pub column_start: usize,
+    pub is_primary: bool,
+    pub label: Option<String>,
+    // The source lines the span covers.
+    pub text: Vec<SpanLine>,
+    // Where the macro this span is in was called.
+    pub expansion: Option<Box<Expansion>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Expansion {
+    pub span: Span,
+}
+
+impl Span {
+    // Spans in a macro of the standard library, like `assert_eq!`, point into
+    // the toolchain's sources. Follow them back to the macro call in the
+    // exercise.
+    fn local(&self) -> &Span {
+        let mut span = self;
+        while Path::new(&span.file_name).is_absolute() || span.file_name.starts_with('<') {
+            match &span.expansion {
+                Some(expansion) => span = &expansion.span,
+                None => break,
+            }
+        }
+        span
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SpanLine {
+    pub text: String,
+    // 1-based columns, the end is exclusive.
+    pub highlight_start: usize,
+    pub highlight_end: usize,
+}
+
+impl Diagnostic {
+    pub fn primary_span(&self) -> Option<&Span> {
+        self.spans.iter().find(|span| span.is_primary)
+    }
+
+    // A rustc-like rendering: the error code or lint, the message, every
+    // source line the diagnostic points at with the offending code underlined
+    // (`^` for the primary span, `-` for the others), then the notes. Well-known
+    // error codes also point at their topic in the book chapter table.
+    pub fn render(&self, book: &Book) -> String {
+        let mut rendered = match &self.code {
+            Some(code) => format!("{}[{}]: {}\n", self.level, code.code, self.message),
+            None => format!("{}: {}\n", self.level, self.message),
+        };
+
+        // Source line number -> the line and its underlines.
+        let mut lines: BTreeMap<usize, (&str, Vec<String>)> = BTreeMap::new();
+        for span in &self.spans {
+            let mark = if span.is_primary { "^" } else { "-" };
+            let local = span.local();
+            for (n, line) in local.text.iter().enumerate() {
+                let width = line.highlight_end.saturating_sub(line.highlight_start);
+                let mut underline = format!(
+                    "{}{}",
+                    " ".repeat(line.highlight_start.saturating_sub(1)),
+                    mark.repeat(width.max(1))
+                );
+                if let Some(label) = span.label.as_ref().filter(|_| n + 1 == local.text.len()) {
+                    underline.push(' ');
+                    underline.push_str(label);
+                }
+                let entry = lines
+                    .entry(local.line_start + n)
+                    .or_insert((&line.text, Vec::new()));
+                entry.1.push(underline);
+            }
+        }
+        let width = lines.keys().last().map_or(0, |line| line.to_string().len());
+        let pad = " ".repeat(width);
+
+        if let Some(span) = self.primary_span().map(Span::local) {
+            let _ = writeln!(
+                rendered,
+                "{pad}--> {}:{}:{}",
+                span.file_name, span.line_start, span.column_start
+            );
+            let _ = writeln!(rendered, "{pad} |");
+        }
+        for (number, (text, underlines)) in &mut lines {
+            underlines.sort_by_key(|underline| underline.len() - underline.trim_start().len());
+            let _ = writeln!(rendered, "{number:>width$} | {text}");
+            for underline in underlines {
+                let _ = writeln!(rendered, "{pad} | {}", underline.trim_end());
+            }
+        }
+
+        for child in &self.children {
+            let _ = writeln!(rendered, "{pad} = {}: {}", child.level, child.message);
+        }
+        if let Some(note) = self.code.as_ref().and_then(|code| book.note(&code.code)) {
+            let _ = writeln!(rendered, "{pad} = book: {note}");
+        }
+        rendered
+    }
+}
+
+// Renders the diagnostics of one exercise under a heading with its name.
+pub fn render_group(exercise: &Exercise, diagnostics: &[Diagnostic], book: &Book) -> String {
+    let mut rendered = format!("── {} ({}) ──\n", exercise.name, exercise.path.display());
+    for diagnostic in diagnostics {
+        rendered.push('\n');
+        rendered.push_str(&diagnostic.render(book));
+    }
+    rendered.push('\n');
+    rendered
+}
+
+fn messages(stdout: &str) -> impl Iterator<Item = CargoMessage> + '_ {
+    stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<CargoMessage>(line).ok())
+}
+
+// Diagnostics without a span, like "aborting due to 2 previous errors", only
+// repeat what the other diagnostics said.
+fn diagnostic(message: CargoMessage) -> Option<(String, Diagnostic)> {
+    if message.reason != "compiler-message" {
+        return None;
+    }
+    match (message.target, message.message) {
+        (Some(target), Some(diagnostic)) if !diagnostic.spans.is_empty() => {
+            Some((target.name, diagnostic))
+        }
+        _ => None,
+    }
+}
+
+// The diagnostics and binaries of a cargo build, by bin name.
+#[derive(Default)]
+pub struct Build {
+    pub diagnostics: HashMap<String, Vec<Diagnostic>>,
+    pub executables: HashMap<String, PathBuf>,
+    pub test_executables: HashMap<String, PathBuf>,
+}
+
+impl Build {
+    pub fn parse(stdout: &str) -> Self {
+        let mut build = Self::default();
+        for message in messages(stdout) {
+            let test = message.profile.as_ref().is_some_and(|profile| profile.test);
+            if message.reason == "compiler-artifact" {
+                let (Some(target), Some(executable)) = (message.target, message.executable) else {
+                    continue;
+                };
+                let executables = if test {
+                    &mut build.test_executables
+                } else {
+                    &mut build.executables
+                };
+                executables.insert(target.name, executable);
+            } else if let Some((name, diagnostic)) = diagnostic(message) {
+                // Compiler messages don't say whether they come from the test
+                // build, so an error outside of the tests is reported twice.
+                let diagnostics = build.diagnostics.entry(name).or_default();
+                if !diagnostics.contains(&diagnostic) {
+                    diagnostics.push(diagnostic);
+                }
+            }
+        }
+        build
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLIPPY1: &str = r#"{"reason":"compiler-message","target":{"name":"clippy1"},"message":{"rendered":"","children":[],"level":"error","message":"approximate value of `f{32, 64}::consts::PI` found","spans":[{"byte_end":364,"byte_start":360,"column_end":18,"column_start":14,"file_name":"exercises/22_clippy/clippy1.rs","is_primary":true,"label":null,"line_end":9,"line_start":9,"text":[{"highlight_end":18,"highlight_start":14,"text":"    let pi = 3.14;"}]}],"code":{"code":"clippy::approx_constant","explanation":null}}}"#;
+
+    #[test]
+    fn groups_the_diagnostics_by_bin() {
+        let other = CLIPPY1.replace("clippy1", "clippy2");
+        let stdout =
+            format!("{CLIPPY1}\n{other}\n{{\"reason\":\"build-finished\",\"success\":false}}\n");
+        let diagnostics = &Build::parse(&stdout).diagnostics["clippy1"];
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].code.as_ref().unwrap().code,
+            "clippy::approx_constant"
+        );
+    }
+
+    #[test]
+    fn renders_the_span() {
+        let diagnostics = &Build::parse(CLIPPY1).diagnostics["clippy1"];
+
+        assert_eq!(
+            diagnostics[0].render(&Book::default()),
+            "error[clippy::approx_constant]: approximate value of `f{32, 64}::consts::PI` found
+ --> exercises/22_clippy/clippy1.rs:9:14
+  |
+9 |     let pi = 3.14;
+  |              ^^^^
+"
+        );
+    }
+}
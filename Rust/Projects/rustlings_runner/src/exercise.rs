@@ -0,0 +1,264 @@
+use anyhow::{bail, ensure, Context, Result};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::expected::ExpectedOutput;
+
+pub const INFO_FILE: &str = "info.toml";
+const FORMAT_VERSION: u32 = 1;
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct InfoFile {
+    format_version: u32,
+    exercises: Vec<ExerciseInfo>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExerciseInfo {
+    name: String,
+    dir: String,
+    book: Option<String>,
+    mode: Mode,
+    #[serde(default)]
+    strict_clippy: bool,
+    expected_output: Option<ExpectedOutput>,
+    // In seconds.
+    timeout: Option<u64>,
+    #[serde(default)]
+    requires: Vec<String>,
+    hints: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    // The exercise's tests have to pass.
+    Test,
+    // The exercise only has to compile and exit successfully.
+    Run,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exercise {
+    pub name: String,
+    pub dir: String,
+    pub book: Option<String>,
+    pub mode: Mode,
+    pub strict_clippy: bool,
+    pub expected_output: Option<ExpectedOutput>,
+    // Overrides the default deadline for running the exercise and its tests.
+    pub timeout: Option<Duration>,
+    // Topics or exercises that have to be done before the exercise's topic
+    // unlocks, see `topics.rs`.
+    pub requires: Vec<String>,
+    // Ordered from the most general hint to the most specific one.
+    pub hints: Vec<String>,
+    // All paths are relative to the root of the exercises crate.
+    pub path: PathBuf,
+    pub solution: PathBuf,
+    // The unsolved exercise, as it was shipped.
+    pub pristine: PathBuf,
+}
+
+impl Exercise {
+    // Solutions are declared next to their exercise as `<name>_sol` bins.
+    pub fn solution_bin(&self) -> String {
+        format!("{}_sol", self.name)
+    }
+}
+
+#[cfg(test)]
+impl Exercise {
+    // An exercise as `info.toml` would declare it with only the required
+    // fields, for tests that need a list without the files.
+    pub fn for_test(dir: &str, name: &str, mode: Mode) -> Self {
+        Self::from(ExerciseInfo {
+            name: name.to_string(),
+            dir: dir.to_string(),
+            book: None,
+            mode,
+            strict_clippy: false,
+            expected_output: None,
+            timeout: None,
+            requires: Vec::new(),
+            hints: vec![String::new()],
+        })
+    }
+}
+
+#[cfg(test)]
+impl ExerciseList {
+    // The exercises checked in next to the runner.
+    pub fn checked_in() -> Self {
+        Self::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join("../rustlings")).unwrap()
+    }
+}
+
+impl From<ExerciseInfo> for Exercise {
+    fn from(info: ExerciseInfo) -> Self {
+        let file = format!("{}.rs", info.name);
+        Self {
+            path: Path::new("exercises").join(&info.dir).join(&file),
+            solution: Path::new("solutions").join(&info.dir).join(&file),
+            pristine: Path::new("pristine").join(&info.dir).join(&file),
+            name: info.name,
+            dir: info.dir,
+            book: info.book,
+            mode: info.mode,
+            strict_clippy: info.strict_clippy,
+            expected_output: info.expected_output,
+            timeout: info.timeout.map(Duration::from_secs),
+            requires: info.requires,
+            hints: info.hints,
+        }
+    }
+}
+
+// The exercises in the order they are declared in `info.toml`.
+pub struct ExerciseList {
+    pub root: PathBuf,
+    pub exercises: Vec<Exercise>,
+}
+
+impl ExerciseList {
+    pub fn load(root: &Path) -> Result<Self> {
+        let info_path = root.join(INFO_FILE);
+        let info = fs::read_to_string(&info_path)
+            .with_context(|| format!("Failed to read {}", info_path.display()))?;
+        let exercises =
+            parse_info(&info).with_context(|| format!("Invalid {}", info_path.display()))?;
+
+        Ok(Self {
+            root: root.to_path_buf(),
+            exercises,
+        })
+    }
+
+    pub fn find(&self, name: &str) -> Result<&Exercise> {
+        match self.exercises.iter().find(|exercise| exercise.name == name) {
+            Some(exercise) => Ok(exercise),
+            None => bail!("No exercise named `{name}` in {}", self.root.display()),
+        }
+    }
+}
+
+pub fn parse_info(info: &str) -> Result<Vec<Exercise>> {
+    let info: InfoFile = toml::from_str(info)?;
+    ensure!(
+        info.format_version == FORMAT_VERSION,
+        "Unsupported format version {}, expected {FORMAT_VERSION}",
+        info.format_version
+    );
+
+    let mut names = HashSet::new();
+    let mut dirs = HashSet::new();
+    for exercise in &info.exercises {
+        // Only earlier exercises can be required, which also rules out cycles.
+        for required in &exercise.requires {
+            ensure!(
+                names.contains(required.as_str()) || dirs.contains(required.as_str()),
+                "The exercise `{}` requires `{required}`, which isn't an earlier topic or exercise",
+                exercise.name
+            );
+        }
+        dirs.insert(exercise.dir.as_str());
+        ensure!(
+            names.insert(exercise.name.as_str()),
+            "The exercise `{}` is declared twice",
+            exercise.name
+        );
+        ensure!(
+            !exercise.name.ends_with("_sol"),
+            "The exercise name `{}` clashes with the solution bins",
+            exercise.name
+        );
+        ensure!(
+            !exercise.hints.is_empty(),
+            "The exercise `{}` has no hints",
+            exercise.name
+        );
+        if let Some(expected) = &exercise.expected_output {
+            ensure!(
+                exercise.mode == Mode::Run,
+                "The exercise `{}` has tests, so it can't declare an expected output",
+                exercise.name
+            );
+            expected
+                .validate()
+                .with_context(|| format!("The exercise `{}`", exercise.name))?;
+        }
+    }
+
+    Ok(info.exercises.into_iter().map(Exercise::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_paths_from_dir_and_name() {
+        let info = r#"
+format_version = 1
+
+[[exercises]]
+name = "clippy1"
+dir = "22_clippy"
+book = "§21.4"
+mode = "run"
+strict_clippy = true
+hints = ["Read the lint.", "Use `std::f32::consts::PI`."]
+"#;
+        let exercises = parse_info(info).unwrap();
+
+        assert_eq!(exercises.len(), 1);
+        assert_eq!(
+            exercises[0].path,
+            Path::new("exercises/22_clippy/clippy1.rs")
+        );
+        assert_eq!(
+            exercises[0].solution,
+            Path::new("solutions/22_clippy/clippy1.rs")
+        );
+        assert_eq!(exercises[0].mode, Mode::Run);
+        assert!(exercises[0].strict_clippy);
+        assert_eq!(exercises[0].hints.len(), 2);
+    }
+
+    #[test]
+    fn rejects_duplicate_exercises() {
+        let entry =
+            "[[exercises]]\nname = \"intro1\"\ndir = \"00_intro\"\nmode = \"run\"\nhints = [\"\"]\n";
+        let info = format!("format_version = 1\n{entry}{entry}");
+        assert!(parse_info(&info).is_err());
+    }
+
+    #[test]
+    fn requires_earlier_topics_or_exercises() {
+        let entry = |name: &str, dir: &str, requires: &str| {
+            format!(
+                "[[exercises]]\nname = \"{name}\"\ndir = \"{dir}\"\nmode = \"run\"\n\
+                 requires = [{requires}]\nhints = [\"\"]\n"
+            )
+        };
+        let info = |entries: &[String]| format!("format_version = 1\n{}", entries.concat());
+        let intro1 = entry("intro1", "00_intro", "");
+
+        let exercises = parse_info(&info(&[
+            intro1.clone(),
+            entry("variables1", "01_variables", "\"intro1\", \"00_intro\""),
+        ]))
+        .unwrap();
+        assert_eq!(exercises[1].requires, ["intro1", "00_intro"]);
+        assert!(parse_info(&info(&[
+            entry("variables1", "01_variables", "\"intro1\""),
+            intro1
+        ]))
+        .is_err());
+    }
+}
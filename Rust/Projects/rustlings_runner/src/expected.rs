@@ -0,0 +1,146 @@
+/*
+Expected output of run-mode exercises.
+
+Exercises without tests pass as soon as they exit successfully, so `arc1`
+would pass while printing the wrong sums. `info.toml` can declare what such an
+exercise has to print, compared against its captured stdout:
+
+    expected_output = { exact = "Jobs done: 10\n" }
+    expected_output = { regex = '(Thread \d done\n){10}' }
+    expected_output = { lines = ["Sum of offset 0 is 624", ...] }
+*/
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::Deserialize;
+use similar::TextDiff;
+use std::fmt::Write;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase", deny_unknown_fields)]
+pub enum ExpectedOutput {
+    Exact(String),
+    // Has to match the whole output, not just a part of it.
+    Regex(String),
+    // The same lines in any order, for exercises printing from several threads.
+    Lines(Vec<String>),
+}
+
+impl ExpectedOutput {
+    fn regex(pattern: &str) -> Result<Regex> {
+        Regex::new(&format!("^(?:{pattern})$"))
+            .with_context(|| format!("Invalid expected output regex `{pattern}`"))
+    }
+
+    // Catches a broken regex when `info.toml` is loaded rather than when the
+    // exercise is first verified.
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Self::Regex(pattern) => Self::regex(pattern).map(|_| ()),
+            Self::Exact(_) | Self::Lines(_) => Ok(()),
+        }
+    }
+
+    // Returns an explanation of the mismatch if `stdout` isn't what was
+    // expected.
+    pub fn check(&self, stdout: &str) -> Result<Option<String>> {
+        let mismatch = match self {
+            Self::Exact(expected) if expected != stdout => Some(
+                TextDiff::from_lines(expected.as_str(), stdout)
+                    .unified_diff()
+                    .header("expected output", "actual output")
+                    .to_string(),
+            ),
+            Self::Exact(_) => None,
+            Self::Regex(pattern) if !Self::regex(pattern)?.is_match(stdout) => Some(format!(
+                "The output doesn't match the expected pattern `{pattern}`\n"
+            )),
+            Self::Regex(_) => None,
+            Self::Lines(expected) => lines_mismatch(expected, stdout),
+        };
+        Ok(mismatch)
+    }
+}
+
+// Compares the lines as multisets, so each expected line has to be printed
+// exactly as often as it is listed.
+fn lines_mismatch(expected: &[String], stdout: &str) -> Option<String> {
+    let mut missing: Vec<&str> = expected.iter().map(String::as_str).collect();
+    let mut unexpected = Vec::new();
+    for line in stdout.lines() {
+        match missing.iter().position(|&expected| expected == line) {
+            Some(index) => {
+                missing.swap_remove(index);
+            }
+            None => unexpected.push(line),
+        }
+    }
+
+    if missing.is_empty() && unexpected.is_empty() {
+        return None;
+    }
+    let mut mismatch = String::new();
+    for line in missing {
+        let _ = writeln!(mismatch, "Missing line: {line}");
+    }
+    for line in unexpected {
+        let _ = writeln!(mismatch, "Unexpected line: {line}");
+    }
+    Some(mismatch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(lines: &[&str]) -> ExpectedOutput {
+        ExpectedOutput::Lines(lines.iter().map(|line| line.to_string()).collect())
+    }
+
+    #[test]
+    fn compares_lines_in_any_order() {
+        let expected = lines(&["Sum of offset 0 is 624", "Sum of offset 1 is 637"]);
+
+        let in_order = "Sum of offset 0 is 624\nSum of offset 1 is 637\n";
+        let reversed = "Sum of offset 1 is 637\nSum of offset 0 is 624\n";
+        assert_eq!(expected.check(in_order).unwrap(), None);
+        assert_eq!(expected.check(reversed).unwrap(), None);
+
+        let wrong_sum = "Sum of offset 1 is 637\nSum of offset 0 is 4950\n";
+        assert_eq!(
+            expected.check(wrong_sum).unwrap().unwrap(),
+            "Missing line: Sum of offset 0 is 624\nUnexpected line: Sum of offset 0 is 4950\n"
+        );
+        let twice = "Sum of offset 0 is 624\nSum of offset 0 is 624\n";
+        assert!(expected.check(twice).unwrap().is_some());
+    }
+
+    #[test]
+    fn matches_the_regex_against_the_whole_output() {
+        let expected = ExpectedOutput::Regex(r"(Thread \d done\n){2}".to_string());
+
+        assert_eq!(
+            expected.check("Thread 1 done\nThread 0 done\n").unwrap(),
+            None
+        );
+        assert!(expected
+            .check("Thread 1 done\nThread 0 done\nOops\n")
+            .unwrap()
+            .is_some());
+        assert!(ExpectedOutput::Regex("(".to_string()).validate().is_err());
+    }
+
+    #[test]
+    fn parses_from_toml() {
+        #[derive(Deserialize)]
+        struct Entry {
+            expected_output: ExpectedOutput,
+        }
+
+        let entry: Entry = toml::from_str(r#"expected_output = { exact = "Hi\n" }"#).unwrap();
+        assert_eq!(
+            entry.expected_output,
+            ExpectedOutput::Exact("Hi\n".to_string())
+        );
+    }
+}
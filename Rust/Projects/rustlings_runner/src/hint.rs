@@ -0,0 +1,37 @@
+use anyhow::Result;
+
+use crate::chapters;
+use crate::exercise::ExerciseList;
+use crate::progress::{self, Progress};
+
+// Reveals the next hint of an exercise and prints every hint revealed so far.
+// Once all hints are revealed, asking again just shows them again.
+pub fn reveal(list: &ExerciseList, name: &str) -> Result<()> {
+    let exercise = list.find(name)?;
+    let mut progress = Progress::load(list)?;
+
+    progress.exercise(&exercise.name).seen(progress::now());
+    let revealed = &mut progress.exercise(&exercise.name).hints_revealed;
+    *revealed = (*revealed + 1).min(exercise.hints.len());
+    let revealed = *revealed;
+    progress.write(list)?;
+
+    let total = exercise.hints.len();
+    for (n, hint) in exercise.hints.iter().take(revealed).enumerate() {
+        println!("Hint {}/{total} for `{}`: {hint}", n + 1, exercise.name);
+    }
+
+    if revealed < total {
+        println!("Ask again for a more specific hint.");
+    } else if let Some(book) = &exercise.book {
+        println!("That was the last hint. The book covers this in {book}.");
+    } else {
+        println!("That was the last hint.");
+    }
+    if revealed == total {
+        if let Some(notes) = chapters::notes(list, exercise) {
+            println!("Your notes on the chapter are in {}", notes.display());
+        }
+    }
+    Ok(())
+}
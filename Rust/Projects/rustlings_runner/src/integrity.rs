@@ -0,0 +1,357 @@
+/*
+Rejects exercises that pass because the code they are checked with was edited.
+
+Two kinds of regions are protected, and fingerprinted in the pristine copy and
+in the learner's file:
+
+- Every item of the test module, like a test function or a `use`, together
+  with the comments and attributes above it. Items with a `TODO` for the
+  learner are left out, like the tests that the exercises in `17_tests` are
+  about writing, or quiz2's `use` that has to be completed.
+- The code marked with a "Don't change" or "Don't modify" comment. The marker
+  is either at the end of the protected line, or on its own line before it:
+  "...this line" protects the next line, "...the lines below" everything up to
+  the next blank line, and anything else ("this function", "the expression
+  below") the item that follows, up to its closing brace.
+
+The fingerprints ignore whitespace and comments, so reformatting or
+commenting the code is fine.
+*/
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::fs;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::Path;
+
+use crate::crosscheck::split_tests;
+use crate::exercise::Exercise;
+
+const MARKERS: &[&str] = &["don't change", "don't modify", "don't edit"];
+
+#[derive(Debug, PartialEq, Eq)]
+struct Region {
+    // Identifies the same region in both files: the marker and how many times
+    // it came before.
+    key: (String, usize),
+    // 1-based, for the messages.
+    line: usize,
+    // The first line of the region, to name it.
+    title: String,
+    // An item of the test module rather than marked code.
+    in_tests: bool,
+    fingerprint: u64,
+}
+
+fn strip_comment(line: &str) -> &str {
+    match line.find("//") {
+        // Naively, `//` in a string is only a comment if the quotes before it
+        // are balanced.
+        Some(start) if line[..start].matches('"').count() % 2 == 0 => &line[..start],
+        _ => line,
+    }
+}
+
+// How many more braces the line opens than it closes, outside of strings and
+// comments.
+fn brace_balance(line: &str) -> isize {
+    let mut balance = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in strip_comment(line).chars() {
+        match c {
+            '"' if !escaped => in_string = !in_string,
+            '{' if !in_string => balance += 1,
+            '}' if !in_string => balance -= 1,
+            _ => (),
+        }
+        escaped = c == '\\' && !escaped;
+    }
+    balance
+}
+
+// The items of a test module, each with the comments and attributes above it,
+// as the index of its first line and its lines.
+fn test_items<'a>(lines: &[&'a str]) -> Vec<(usize, Vec<&'a str>)> {
+    let mut items = Vec::new();
+    let mut item = Vec::new();
+    let mut start = 0;
+    let mut depth = 0;
+    for (n, line) in lines.iter().enumerate() {
+        let before = depth;
+        depth += brace_balance(line);
+        // `#[cfg(test)]`, `mod tests {` and its closing brace.
+        if before == 0 || depth == 0 {
+            continue;
+        }
+        if before == 1 && line.trim().is_empty() {
+            continue;
+        }
+        if item.is_empty() {
+            start = n;
+        }
+        item.push(*line);
+        let code = strip_comment(line).trim();
+        if depth == 1 && !code.starts_with("#[") && (code.ends_with('}') || code.ends_with(';')) {
+            items.push((start, std::mem::take(&mut item)));
+        }
+    }
+    items
+}
+
+fn fingerprint<'a>(lines: impl IntoIterator<Item = &'a str>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for line in lines {
+        for token in strip_comment(line).split_whitespace() {
+            token.hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+fn indent(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
+
+// The lines a marker on its own line protects, starting at the next line.
+fn marked_lines<'a>(marker: &str, below: &'a [&'a str]) -> &'a [&'a str] {
+    let Some(start) = below.iter().position(|line| !line.trim().is_empty()) else {
+        return &[];
+    };
+    let below = &below[start..];
+    let end = if marker.contains("this line") {
+        1
+    } else if marker.contains("lines below") {
+        below
+            .iter()
+            .position(|line| line.trim().is_empty())
+            .unwrap_or(below.len())
+    } else if below[0].trim_end().ends_with(';') {
+        1
+    } else {
+        let closing = format!("{}}}", indent(below[0]));
+        below
+            .iter()
+            .position(|line| line.trim_end() == closing)
+            .map_or(below.len(), |end| end + 1)
+    };
+    &below[..end]
+}
+
+fn regions(source: &str) -> Vec<Region> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut regions = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+
+    for (n, line) in lines.iter().enumerate() {
+        let Some(start) = line.find("//") else {
+            continue;
+        };
+        let comment = line[start + 2..].trim();
+        let marker = comment.to_lowercase();
+        if !MARKERS.iter().any(|known| marker.starts_with(known)) {
+            continue;
+        }
+        // The test module is covered as a whole.
+        if marker.contains("tests") {
+            continue;
+        }
+
+        let count = seen.entry(marker.clone()).or_default();
+        let key = (comment.to_string(), *count);
+        *count += 1;
+
+        let (line, protected) = if line[..start].trim().is_empty() {
+            (n + 2, marked_lines(&marker, &lines[n + 1..]))
+        } else {
+            (n + 1, &lines[n..=n])
+        };
+        regions.push(Region {
+            key,
+            line,
+            title: protected.first().map_or("", |line| line.trim()).to_string(),
+            in_tests: false,
+            fingerprint: fingerprint(protected.iter().copied()),
+        });
+    }
+
+    if let (code, Some(tests)) = split_tests(source) {
+        let offset = code.lines().count();
+        let lines: Vec<&str> = tests.lines().collect();
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        for (start, item) in test_items(&lines) {
+            if item.iter().any(|line| line.contains("TODO")) {
+                continue;
+            }
+            // Named after its first line of code, like `fn costs()`.
+            let (n, code) = item
+                .iter()
+                .map(|line| strip_comment(line).trim())
+                .enumerate()
+                .find(|(_, code)| !code.is_empty() && !code.starts_with("#["))
+                .unwrap_or_default();
+            let title = code.trim_end_matches('{').trim_end().to_string();
+            let count = seen.entry(title.clone()).or_default();
+            regions.push(Region {
+                key: (title.clone(), *count),
+                line: offset + start + n + 1,
+                title,
+                in_tests: true,
+                fingerprint: fingerprint(item),
+            });
+            *count += 1;
+        }
+    }
+    regions
+}
+
+// Describes every protected region of the pristine exercise that was changed
+// in `source`.
+fn tampered(pristine: &str, source: &str) -> Vec<String> {
+    let changed = regions(source);
+    regions(pristine)
+        .into_iter()
+        .filter(|region| {
+            !changed.iter().any(|other| {
+                other.in_tests == region.in_tests
+                    && other.key == region.key
+                    && other.fingerprint == region.fingerprint
+            })
+        })
+        .map(|region| {
+            if region.in_tests {
+                format!(
+                    "`{}` in the tests (line {}) was changed",
+                    region.title, region.line
+                )
+            } else {
+                format!(
+                    "`{}` (line {}) was changed, but it's marked \"{}\"",
+                    region.title, region.line, region.key.0
+                )
+            }
+        })
+        .collect()
+}
+
+// Compares the exercise with its pristine copy. Exercises without one, like
+// those of a pack that doesn't ship `pristine/`, can't be checked.
+pub fn check(root: &Path, exercise: &Exercise) -> Result<Vec<String>> {
+    let pristine = root.join(&exercise.pristine);
+    if !pristine.exists() {
+        return Ok(Vec::new());
+    }
+    let pristine = fs::read_to_string(&pristine)
+        .with_context(|| format!("Failed to read {}", pristine.display()))?;
+    let path = root.join(&exercise.path);
+    let source =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    Ok(tampered(&pristine, &source))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::ExerciseList;
+
+    const ERRORS3: &str = "use std::num::ParseIntError;
+
+// Don't change this function.
+fn total_cost(item_quantity: &str) -> Result<i32, ParseIntError> {
+    let qty = item_quantity.parse::<i32>()?;
+    Ok(qty * 5 + 1)
+}
+
+fn main() {
+    let mut tokens = 100;
+    // Don't change this line.
+    let cost = total_cost(\"8\")?;
+    tokens -= cost; // Don't change this line
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn costs() {
+        assert_eq!(super::total_cost(\"2\"), Ok(11));
+    }
+}
+";
+
+    #[test]
+    fn accepts_solutions_and_reformatting() {
+        let solved = ERRORS3
+            .replace("fn main() {", "fn main() -> Result<(), ParseIntError> {")
+            .replace(
+                "    let mut tokens = 100;",
+                "    let mut tokens = 100;\n    Ok(())",
+            )
+            .replace("Ok(qty * 5 + 1)", "Ok(qty  *  5 + 1) // Reformatted");
+
+        assert_eq!(tampered(ERRORS3, &solved), Vec::<String>::new());
+    }
+
+    #[test]
+    fn rejects_edited_tests_and_functions() {
+        let edited = ERRORS3
+            .replace("Ok(11)", "Ok(10)")
+            .replace("qty * 5", "qty * 4")
+            .replace("let cost = total_cost(\"8\")?;", "let cost = 0;");
+
+        assert_eq!(
+            tampered(ERRORS3, &edited),
+            [
+                "`fn total_cost(item_quantity: &str) -> Result<i32, ParseIntError> {` (line 4) \
+                 was changed, but it's marked \"Don't change this function.\"",
+                "`let cost = total_cost(\"8\")?;` (line 12) was changed, but it's marked \
+                 \"Don't change this line.\"",
+                "`fn costs()` in the tests (line 19) was changed",
+            ]
+        );
+    }
+
+    #[test]
+    fn only_the_tests_with_a_todo_can_be_edited() {
+        let pristine = "fn area(width: u32, height: u32) -> u32 {
+    width * height
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn square() {
+        assert_eq!(area(2, 2), 4);
+    }
+
+    #[test]
+    fn empty() {
+        // TODO: Test that a rectangle without width has no area.
+    }
+}
+";
+        let written = pristine.replace(
+            "        // TODO: Test that a rectangle without width has no area.",
+            "        assert_eq!(area(0, 3), 0);",
+        );
+        assert_eq!(tampered(pristine, &written), Vec::<String>::new());
+
+        let loosened = written.replace("assert_eq!(area(2, 2), 4);", "");
+        assert_eq!(
+            tampered(pristine, &loosened),
+            ["`fn square()` in the tests (line 10) was changed"]
+        );
+    }
+
+    #[test]
+    fn the_checked_in_exercises_are_untouched() {
+        let list = ExerciseList::checked_in();
+        let root = &list.root;
+
+        for exercise in &list.exercises {
+            let tampered = check(root, exercise).unwrap();
+            assert!(tampered.is_empty(), "{}: {tampered:?}", exercise.name);
+        }
+    }
+}
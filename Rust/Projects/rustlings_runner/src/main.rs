@@ -0,0 +1,398 @@
+/*
+A small local replacement for the `rustlings` CLI.
+
+It reads the ordered list of exercises from `Projects/rustlings/info.toml`,
+then compiles, runs and tests them with cargo. The `[[bin]]` entries in
+`Projects/rustlings/Cargo.toml` are generated from the same file.
+
+    cargo run -p rustlings_runner -- list
+    cargo run -p rustlings_runner -- run intro1
+    cargo run -p rustlings_runner -- verify
+    cargo run -p rustlings_runner -- verify-all --format junit --output report.xml
+    cargo run -p rustlings_runner -- watch
+    cargo run -p rustlings_runner -- sandbox move_semantics2
+    cargo run -p rustlings_runner -- hint errors6
+    cargo run -p rustlings_runner -- chapter structs1 --run
+    cargo run -p rustlings_runner -- stats
+    cargo run -p rustlings_runner -- topics
+    cargo run -p rustlings_runner -- serve
+    cargo run -p rustlings_runner -- solution quiz1
+    cargo run -p rustlings_runner -- reset enums3
+    cargo run -p rustlings_runner -- review --check
+    cargo run -p rustlings_runner -- profile switch alice
+    cargo run -p rustlings_runner -- cargo-toml
+    cargo run -p rustlings_runner -- new-exercise 24_our_patterns builders1
+    cargo run -p rustlings_runner -- check-solutions
+    cargo run -p rustlings_runner -- mutate quiz1
+    cargo run -p rustlings_runner -- --pack async watch
+*/
+
+mod book;
+mod cargo_toml;
+mod chapters;
+mod crosscheck;
+mod diagnostic;
+mod exercise;
+mod expected;
+mod hint;
+mod integrity;
+mod mutate;
+mod pack;
+mod process;
+mod profile;
+mod progress;
+mod reset;
+mod review;
+mod sandbox;
+mod scaffold;
+#[cfg(feature = "serve")]
+mod serve;
+mod solution;
+mod state;
+mod stats;
+mod topics;
+mod verify;
+mod verify_all;
+mod watch;
+
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use exercise::ExerciseList;
+use mutate::Verdict;
+use progress::Progress;
+use state::ProgressState;
+
+#[derive(Parser)]
+#[command(name = "rustlings", about = "Run and verify the rustlings exercises")]
+struct Args {
+    /// Directory of the `exercises` crate
+    #[arg(long, global = true, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/../rustlings"))]
+    root: PathBuf,
+    /// Work on an exercise pack instead, by name in `packs/` or by path
+    #[arg(long, global = true)]
+    pack: Option<String>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Compile, run and test a single exercise
+    Run { name: String },
+    /// Verify all exercises in order, stopping at the first failure, and
+    /// record the progress in `.rustlings-state.txt`
+    Verify,
+    /// Verify every exercise in parallel and write a report, without touching
+    /// `.rustlings-state.txt`
+    VerifyAll {
+        #[arg(long, value_enum, default_value = "json")]
+        format: verify_all::Format,
+        /// Where to write the report (stdout by default)
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Number of exercises to run at the same time (the CPU count by default)
+        #[arg(long)]
+        jobs: Option<usize>,
+        /// Seconds an exercise or its tests may run before they are killed,
+        /// unless `info.toml` sets a `timeout` for the exercise
+        #[arg(long, default_value_t = verify::DEFAULT_TIMEOUT.as_secs())]
+        timeout: u64,
+    },
+    /// List all exercises in order with their progress
+    List,
+    /// List the exercise packs in `packs/` with their progress
+    Packs,
+    /// Re-verify the current exercise whenever it is saved
+    Watch,
+    /// Experiment with a copy of an exercise in a throwaway cargo project
+    /// that re-runs `main` whenever it is saved
+    Sandbox { name: String },
+    /// Reveal the next hint of an exercise (the current one by default)
+    Hint { name: Option<String> },
+    /// Point to the book sections and our chapter crate for an exercise (the
+    /// current one by default)
+    Chapter {
+        name: Option<String>,
+        /// Run the chapter crate's binary
+        #[arg(long)]
+        run: bool,
+    },
+    /// Show the time spent and the attempts per topic, slowest first
+    Stats,
+    /// Show the topics and quizzes in order with what they are waiting for
+    Topics,
+    /// Serve a progress dashboard on localhost
+    #[cfg(feature = "serve")]
+    Serve {
+        #[arg(long, default_value_t = 7878)]
+        port: u16,
+    },
+    /// Show how your solution differs from the reference solution
+    Solution {
+        name: String,
+        /// Show the solution even if the exercise isn't done yet
+        #[arg(long)]
+        force: bool,
+    },
+    /// Restore an exercise to its unsolved state and mark it as not done
+    Reset { name: String },
+    /// Solve a done exercise again when it's due for review, in a scratch
+    /// copy that doesn't touch your progress
+    Review {
+        /// Check the copy that's being reviewed and reschedule it
+        #[arg(long, conflicts_with = "give_up")]
+        check: bool,
+        /// Reschedule the exercise that's being reviewed as forgotten
+        #[arg(long)]
+        give_up: bool,
+    },
+    /// List the learner profiles, or create one or switch to it
+    Profile {
+        #[command(subcommand)]
+        command: Option<ProfileCommand>,
+    },
+    /// Check that every reference solution passes its exercise's tests
+    CheckSolutions,
+    /// Check that the tests of a solved exercise catch small bugs in it
+    ///
+    /// Exits with 1 when some mutants survive, and with 2 when none of them
+    /// compiled.
+    Mutate { name: String },
+    /// Regenerate the `[[bin]]` list in `Cargo.toml` from `info.toml`
+    CargoToml {
+        /// Only check that `Cargo.toml` is up to date
+        #[arg(long)]
+        check: bool,
+    },
+    /// Scaffold a new exercise with its solution, `info.toml` entry and
+    /// `Cargo.toml` bins
+    NewExercise {
+        /// Topic directory in `exercises/`, like `24_our_patterns`
+        dir: String,
+        name: String,
+        #[arg(long, value_enum, default_value = "test")]
+        mode: exercise::Mode,
+        /// Sections of the Rust book the exercise is based on
+        #[arg(long)]
+        book: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ProfileCommand {
+    /// Create a profile that starts from the unsolved exercises
+    New { name: String },
+    /// Store the active profile's exercises and progress, and bring back the
+    /// ones of another profile
+    Switch { name: String },
+}
+
+fn main() -> Result<ExitCode> {
+    let args = Args::parse();
+    let root = match &args.pack {
+        Some(pack) => pack::resolve(&args.root, pack)?,
+        None => args.root.clone(),
+    };
+    let list = ExerciseList::load(&root)?;
+
+    match args.command {
+        Command::Run { name } => {
+            let exercise = list.find(&name)?;
+            topics::ensure_unlocked(&list, exercise)?;
+            let report = verify::verify(&list.root, exercise)?;
+            Progress::record(&list, &exercise.name, report.outcome)?;
+            print!("{}", report.output);
+            println!("`{}` {}", exercise.name, report.outcome.describe());
+
+            if !report.outcome.passed() {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Verify => {
+            let mut state = ProgressState::load_or_fresh(&list)?;
+            let mut progress = Progress::load(&list)?;
+            for exercise in &list.exercises {
+                let report = verify::verify(&list.root, exercise)?;
+                let now = progress::now();
+                progress
+                    .exercise(&exercise.name)
+                    .record(report.outcome, now);
+                if !report.outcome.passed() {
+                    state.current = exercise.name.clone();
+                    state.write(&list)?;
+                    progress.write(&list)?;
+
+                    print!("{}", report.output);
+                    println!("`{}` {}", exercise.name, report.outcome.describe());
+                    return Ok(ExitCode::FAILURE);
+                }
+                state.mark_done(&exercise.name);
+                progress.exercise(&exercise.name).complete(now);
+                println!("✓ {}", exercise.name);
+            }
+            state.write(&list)?;
+            progress.write(&list)?;
+            println!("All {} exercises passed!", list.exercises.len());
+        }
+        Command::VerifyAll {
+            format,
+            output,
+            jobs,
+            timeout,
+        } => {
+            let jobs = jobs.unwrap_or_else(|| {
+                std::thread::available_parallelism().map_or(1, |jobs| jobs.get())
+            });
+            let options = verify_all::Options {
+                jobs,
+                timeout: Duration::from_secs(timeout),
+            };
+            let summary = verify_all::verify_all(&list, &options)?;
+            let report = verify_all::render(&summary, format)?;
+            match output {
+                Some(path) => fs::write(&path, report)
+                    .with_context(|| format!("Failed to write {}", path.display()))?,
+                None => print!("{report}"),
+            }
+
+            eprintln!("{}/{} exercises passed", summary.passed, summary.total);
+            if summary.passed < summary.total {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::List => {
+            let state = ProgressState::load_or_fresh(&list)?;
+            let graph = topics::Graph::load(&list)?;
+            for (n, exercise) in list.exercises.iter().enumerate() {
+                // Two columns wide, the lock is a wide character.
+                let status = if exercise.name == state.current {
+                    "->"
+                } else if state.is_done(&exercise.name) {
+                    "✓ "
+                } else if !graph.missing(exercise, &state).is_empty() {
+                    "🔒"
+                } else {
+                    "  "
+                };
+                println!(
+                    "{:>3} {} {:<20} {}",
+                    n + 1,
+                    status,
+                    exercise.name,
+                    exercise.path.display()
+                );
+            }
+            println!("{}/{} done", state.done.len(), list.exercises.len());
+        }
+        Command::Packs => pack::show(&args.root)?,
+        Command::Watch => watch::watch(&list)?,
+        Command::Sandbox { name } => sandbox::sandbox(&list, &name)?,
+        Command::Hint { name } => {
+            let name = match name {
+                Some(name) => name,
+                None => ProgressState::load_or_fresh(&list)?.current,
+            };
+            hint::reveal(&list, &name)?;
+        }
+        Command::Chapter { name, run } => {
+            let name = match name {
+                Some(name) => name,
+                None => ProgressState::load_or_fresh(&list)?.current,
+            };
+            if !run {
+                chapters::show(&list, &name)?;
+            } else if !chapters::run(&list, &name)?.success() {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Stats => stats::show(&list)?,
+        Command::Topics => topics::show(&list)?,
+        #[cfg(feature = "serve")]
+        Command::Serve { port } => serve::serve(&list, port)?,
+        Command::Solution { name, force } => {
+            if !solution::show(&list, &name, force)? {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Reset { name } => {
+            reset::reset(&list, &name)?;
+            println!("`{name}` was reset to its unsolved state");
+        }
+        Command::Review { check, give_up } => {
+            if (check || give_up) && !review::finish(&list, give_up)? {
+                return Ok(ExitCode::FAILURE);
+            } else if !check && !give_up {
+                review::start(&list)?;
+            }
+        }
+        Command::Profile { command } => match command {
+            None => {
+                let active = profile::active(&list.root)?;
+                for name in profile::names(&list.root)? {
+                    let marker = if name == active { "->" } else { "" };
+                    println!("{marker:<2} {name}");
+                }
+            }
+            Some(ProfileCommand::New { name }) => {
+                profile::create(&list, &name)?;
+                println!("Created the profile `{name}`");
+            }
+            Some(ProfileCommand::Switch { name }) => {
+                profile::switch(&list, &name)?;
+                println!("Switched to the profile `{name}`");
+            }
+        },
+        Command::CheckSolutions => {
+            let failed = crosscheck::check_all(&list)?;
+            if !failed.is_empty() {
+                println!("{} solutions fail their exercise's tests", failed.len());
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Mutate { name } => match mutate::mutate(&list, &name)? {
+            Verdict::AllCaught => println!("The tests of `{name}` caught every mutant"),
+            Verdict::Survived(survived) => {
+                println!("{survived} mutants survived, the tests of `{name}` could be stricter");
+                return Ok(ExitCode::FAILURE);
+            }
+            // Distinct from surviving mutants: nothing was learned about the tests.
+            Verdict::NoMutants => {
+                println!("No mutants compiled, so they say nothing about the tests of `{name}`");
+                return Ok(ExitCode::from(2));
+            }
+        },
+        Command::CargoToml { check } => {
+            let up_to_date = cargo_toml::update(&list, check)?;
+            if up_to_date {
+                println!("Cargo.toml is up to date");
+            } else if check {
+                println!("Cargo.toml is out of date with info.toml");
+                return Ok(ExitCode::FAILURE);
+            } else {
+                println!("Updated Cargo.toml from info.toml");
+            }
+        }
+        Command::NewExercise {
+            dir,
+            name,
+            mode,
+            book,
+        } => {
+            let new = scaffold::NewExercise {
+                dir: &dir,
+                name: &name,
+                mode,
+                book: book.as_deref(),
+            };
+            scaffold::scaffold(&list, &new)?;
+            println!("Created `{name}` in exercises/{dir}, fill in the TODOs");
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
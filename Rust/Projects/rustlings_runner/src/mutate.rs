@@ -0,0 +1,452 @@
+/*
+Mutation testing: checks that an exercise's tests catch small bugs in a
+solution.
+
+Each mutant changes one spot of the learner's solved exercise, outside of
+`main` and the test module:
+
+- a comparison is flipped between `<=` and `<`, or `>=` and `>`;
+- `+` and `-` (and `+=` and `-=`) are swapped;
+- a function returns `Default::default()` instead of running its body, and a
+  `return` statement returns the default value.
+
+Every mutant is compiled with `rustc --test` in a scratch directory and its
+tests are run. A mutant is caught when a test fails (or it hangs), and it
+survives when all tests still pass, which means the tests don't pin that part
+of the solution down. Mutants that don't compile, like a default for a type
+without one, say nothing about the tests and are only counted, and when none
+of them compile there is no verdict. Functions whose body can't be found,
+because the file isn't formatted like rustfmt would, are listed instead of
+mutated.
+*/
+
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use crate::crosscheck::{rustc, split_tests, WorkDir};
+use crate::exercise::{ExerciseList, Mode};
+use crate::process;
+use crate::verify::DEFAULT_TIMEOUT;
+
+// Binary operators are surrounded by spaces after rustfmt, unlike the angle
+// brackets of generics.
+const SWAPS: &[(&str, &str)] = &[
+    (" <= ", " < "),
+    (" < ", " <= "),
+    (" >= ", " > "),
+    (" > ", " >= "),
+    (" + ", " - "),
+    (" - ", " + "),
+    (" += ", " -= "),
+    (" -= ", " += "),
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    // None of the mutants compiled, or there were none.
+    NoMutants,
+    AllCaught,
+    Survived(usize),
+}
+
+#[derive(Debug)]
+struct Mutant {
+    // 1-based.
+    line: usize,
+    description: String,
+    source: String,
+}
+
+// Whether `column` of the line is code, not in a string or a comment.
+fn is_code(line: &str, column: usize) -> bool {
+    let before = &line[..column];
+    before.matches('"').count() % 2 == 0 && !before.contains("//")
+}
+
+fn indent(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
+
+// The line that ends the signature starting at `start`, with the `{` of the
+// body, a one-line body or the `;` of a declaration. Signatures are wrapped
+// over several lines when they are long or have a `where` clause.
+fn signature_end(lines: &[&str], start: usize) -> Option<usize> {
+    let end = lines[start..]
+        .iter()
+        .position(|line| line.trim_end().ends_with(['{', '}', ';']))?;
+    Some(start + end)
+}
+
+// The range of lines from the `{` that ends the signature starting at `start`
+// to the closing brace at the signature's indentation, excluding both.
+fn body(lines: &[&str], start: usize) -> Option<(usize, usize)> {
+    let open = signature_end(lines, start).filter(|&end| lines[end].trim_end().ends_with('{'))?;
+    let closing = format!("{}}}", indent(lines[start]));
+    let end = lines[open + 1..]
+        .iter()
+        .position(|line| line.trim_end() == closing)?;
+    Some((open + 1, open + 1 + end))
+}
+
+// The mutants of the solution, and the functions returning a value whose body
+// wasn't found.
+fn mutants(source: &str) -> (Vec<Mutant>, Vec<String>) {
+    let (code, tests) = split_tests(source);
+    let tests = tests.unwrap_or_default();
+    let lines: Vec<&str> = code.lines().collect();
+    let function = Regex::new(r"^\s*(?:pub(?:\([^)]*\))? )?(?:const )?fn (\w+)\b").unwrap();
+    let ret = Regex::new(r"\breturn (.+?);").unwrap();
+
+    let main = lines
+        .iter()
+        .position(|line| line.starts_with("fn main("))
+        .and_then(|start| body(&lines, start))
+        .unwrap_or_default();
+
+    let mut mutants = Vec::new();
+    let mut skipped = Vec::new();
+    let mut mutate = |n: usize, description: String, replaced: Vec<String>| {
+        let mut source = String::new();
+        for line in &lines[..n] {
+            source.push_str(line);
+            source.push('\n');
+        }
+        for line in replaced {
+            source.push_str(&line);
+            source.push('\n');
+        }
+        mutants.push((n, description, source));
+    };
+
+    for (n, line) in lines.iter().enumerate() {
+        if (main.0..main.1).contains(&n) || line.trim_start().starts_with("//") {
+            continue;
+        }
+        let rest = || lines[n + 1..].iter().map(|line| line.to_string());
+
+        for &(from, to) in SWAPS {
+            for (column, _) in line.match_indices(from).filter(|&(c, _)| is_code(line, c)) {
+                let mutated = format!("{}{to}{}", &line[..column], &line[column + from.len()..]);
+                let description = format!("`{}` → `{}`", from.trim(), to.trim());
+                mutate(
+                    n,
+                    description,
+                    std::iter::once(mutated).chain(rest()).collect(),
+                );
+            }
+        }
+
+        if let Some(found) = ret
+            .captures(line)
+            .filter(|c| is_code(line, c.get(0).unwrap().start()))
+        {
+            let value = found.get(1).unwrap();
+            let mutated = format!(
+                "{}Default::default(){}",
+                &line[..value.start()],
+                &line[value.end()..]
+            );
+            let description = format!("`return {}` → `return Default::default()`", value.as_str());
+            mutate(
+                n,
+                description,
+                std::iter::once(mutated).chain(rest()).collect(),
+            );
+        }
+
+        if let Some(found) = function.captures(line).filter(|c| &c[1] != "main") {
+            let name = &found[1];
+            let Some(end) = signature_end(&lines, n) else {
+                skipped.push(name.to_string());
+                continue;
+            };
+            // Functions without a return value have nothing to default.
+            let signature = lines[n..=end].concat();
+            let Some(arrow) = signature.find("->") else {
+                continue;
+            };
+            let description = format!("`{name}` returns `Default::default()`");
+            let one_line = signature[arrow..]
+                .find('{')
+                .filter(|_| end == n && line.trim_end().ends_with('}'));
+            if let Some(open) = one_line {
+                let mutated = format!("{} Default::default() }}", &line[..=arrow + open]);
+                mutate(
+                    n,
+                    description,
+                    std::iter::once(mutated).chain(rest()).collect(),
+                );
+            } else if let Some((start, end)) = body(&lines, n) {
+                let mut replaced: Vec<String> = lines[n..start]
+                    .iter()
+                    .map(|line| line.to_string())
+                    .collect();
+                replaced.push(format!("{}    Default::default()", indent(line)));
+                replaced.extend(lines[end..].iter().map(|line| line.to_string()));
+                mutate(n, description, replaced);
+            } else if !lines[end].trim_end().ends_with(';') {
+                skipped.push(name.to_string());
+            }
+        }
+    }
+
+    let mutants = mutants
+        .into_iter()
+        .map(|(n, description, mut source)| {
+            source.push_str(tests);
+            Mutant {
+                line: n + 1,
+                description,
+                source,
+            }
+        })
+        .collect();
+    (mutants, skipped)
+}
+
+// Whether the tests in `source` pass, or `None` if it doesn't compile.
+fn run_tests(source: &str, name: &str, work_dir: &Path, timeout: Duration) -> Result<Option<bool>> {
+    let source_path = work_dir.join(format!("{name}.rs"));
+    let binary = work_dir.join(name);
+    fs::write(&source_path, source)
+        .with_context(|| format!("Failed to write {}", source_path.display()))?;
+
+    let mut rustc = rustc();
+    rustc
+        .args([
+            "--edition",
+            "2021",
+            "--crate-name",
+            name,
+            "-A",
+            "warnings",
+            "--test",
+        ])
+        .arg(&source_path)
+        .arg("-o")
+        .arg(&binary);
+    if !process::run(&mut rustc, Duration::from_secs(60))?.success() {
+        return Ok(None);
+    }
+    Ok(Some(
+        process::run(&mut Command::new(&binary), timeout)?.success(),
+    ))
+}
+
+// Prints a line per mutant and returns how many of those that compiled the
+// tests let through.
+pub fn mutate(list: &ExerciseList, name: &str) -> Result<Verdict> {
+    let exercise = list.find(name)?;
+    if exercise.mode != Mode::Test {
+        bail!("`{name}` has no tests that could catch mutants");
+    }
+    let path = list.root.join(&exercise.path);
+    let source =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+
+    let work_dir = WorkDir::new()?;
+    let timeout = exercise.timeout.unwrap_or(DEFAULT_TIMEOUT);
+    if run_tests(&source, &exercise.name, work_dir.path(), timeout)? != Some(true) {
+        bail!("The tests of `{name}` have to pass before mutating it");
+    }
+
+    let (mutants, skipped) = mutants(&source);
+    println!("Mutating `{name}` in {} ways", mutants.len());
+    for function in &skipped {
+        println!("- `{function}` isn't formatted like rustfmt would, so its body isn't mutated");
+    }
+    let (mut caught, mut survived, mut broken) = (0, 0, 0);
+    for mutant in &mutants {
+        let location = format!("{}:{}", exercise.path.display(), mutant.line);
+        match run_tests(&mutant.source, &exercise.name, work_dir.path(), timeout)? {
+            Some(false) => {
+                caught += 1;
+                println!("✓ {location} {} was caught", mutant.description);
+            }
+            Some(true) => {
+                survived += 1;
+                println!("✗ {location} {} survived", mutant.description);
+            }
+            None => {
+                broken += 1;
+                println!("- {location} {} doesn't compile", mutant.description);
+            }
+        }
+    }
+
+    if caught + survived == 0 {
+        return Ok(Verdict::NoMutants);
+    }
+    println!(
+        "The tests caught {caught} of {} mutants ({broken} didn't compile)",
+        caught + survived
+    );
+    if survived == 0 {
+        Ok(Verdict::AllCaught)
+    } else {
+        Ok(Verdict::Survived(survived))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::Exercise;
+
+    const QUIZ1: &str = "fn calculate_price_of_apples(amount: i32) -> i32 {
+    if amount <= 40 {
+        return amount * 2;
+    }
+    amount
+}
+
+fn main() {
+    let total = 1 + 2; // main isn't tested
+    println!(\"a + b\");
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn price() {
+        assert_eq!(super::calculate_price_of_apples(41), 41);
+    }
+}
+";
+
+    #[test]
+    fn mutates_the_solution_outside_of_main_and_the_tests() {
+        let (mutants, skipped) = mutants(QUIZ1);
+        assert!(skipped.is_empty());
+        let descriptions: Vec<(usize, &str)> = mutants
+            .iter()
+            .map(|mutant| (mutant.line, mutant.description.as_str()))
+            .collect();
+
+        assert_eq!(
+            descriptions,
+            [
+                (
+                    1,
+                    "`calculate_price_of_apples` returns `Default::default()`"
+                ),
+                (2, "`<=` → `<`"),
+                (3, "`return amount * 2` → `return Default::default()`"),
+            ]
+        );
+        assert!(mutants[1].source.contains("    if amount < 40 {\n"));
+        assert!(mutants[0]
+            .source
+            .starts_with("fn calculate_price_of_apples(amount: i32) -> i32 {\n    Default::default()\n}\n\nfn main()"));
+        assert!(mutants.iter().all(|mutant| mutant
+            .source
+            .ends_with("super::calculate_price_of_apples(41), 41);\n    }\n}\n")));
+    }
+
+    #[test]
+    fn defaults_one_line_and_wrapped_functions() {
+        let source = "fn double(x: i32) -> i32 { x * 2 }
+
+fn largest<T>(items: &[T]) -> Option<&T>
+where
+    T: Ord,
+{
+    items.iter().max()
+}
+
+fn report(x: i32) {
+    println!(\"{x}\");
+}
+
+fn broken() -> i32 {
+  1 }
+";
+        let (mutants, skipped) = mutants(source);
+        let descriptions: Vec<&str> = mutants
+            .iter()
+            .map(|mutant| mutant.description.as_str())
+            .collect();
+
+        assert_eq!(
+            descriptions,
+            [
+                "`double` returns `Default::default()`",
+                "`largest` returns `Default::default()`",
+            ]
+        );
+        assert!(mutants[0]
+            .source
+            .starts_with("fn double(x: i32) -> i32 { Default::default() }\n\nfn largest"));
+        assert!(mutants[1]
+            .source
+            .contains("where\n    T: Ord,\n{\n    Default::default()\n}\n\nfn report"));
+        assert_eq!(skipped, ["broken"]);
+    }
+
+    #[test]
+    fn tells_surviving_mutants_from_no_mutants() {
+        let root = WorkDir::new().unwrap();
+        let exercise = Exercise::for_test("17_tests", "tests1", Mode::Test);
+        let path = root.path().join(&exercise.path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let list = ExerciseList {
+            root: root.path().to_path_buf(),
+            exercises: vec![exercise],
+        };
+        let verdict = |source: &str| {
+            fs::write(&path, source).unwrap();
+            mutate(&list, "tests1").unwrap()
+        };
+
+        let tests = "
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn double() {
+        assert_eq!(super::double(2).get(), 4);
+    }
+}
+";
+        let double = "use std::num::NonZeroU32;
+
+fn double(x: u32) -> NonZeroU32 {
+    NonZeroU32::new(x * 2).unwrap()
+}
+";
+        // A `NonZeroU32` has no default, so no mutant compiles.
+        assert_eq!(verdict(&format!("{double}{tests}")), Verdict::NoMutants);
+
+        let tests = tests.replace(".get()", "");
+        let double = "fn double(x: u32) -> u32 {
+    if x > 100 {
+        return 200;
+    }
+    x * 2
+}
+";
+        // Neither the `>` nor the early `return` is tested.
+        assert_eq!(verdict(&format!("{double}{tests}")), Verdict::Survived(2));
+        let double = "fn double(x: u32) -> u32 {
+    x * 2
+}
+";
+        assert_eq!(verdict(&format!("{double}{tests}")), Verdict::AllCaught);
+    }
+
+    #[test]
+    fn defaults_only_the_returned_value() {
+        let (mutants, _) = mutants("fn f(x: i32) -> i32 {\n    return x; // not x;\n}\n");
+
+        assert_eq!(
+            mutants[1].description,
+            "`return x` → `return Default::default()`"
+        );
+        assert!(mutants[1]
+            .source
+            .contains("    return Default::default(); // not x;\n"));
+    }
+}
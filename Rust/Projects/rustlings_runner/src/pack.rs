@@ -0,0 +1,96 @@
+/*
+Third-party exercise packs, like an async pack or one based on our own code.
+
+A pack is a directory laid out like the built-in exercises crate:
+
+    packs/async/
+        info.toml      the exercises of the pack, in order
+        Cargo.toml     generated from info.toml by `cargo-toml`
+        exercises/     the exercises to solve
+        solutions/     the reference solutions
+        pristine/      the unsolved exercises, for `reset` (optional)
+
+Every command works on a pack when it's given `--pack async`, with a pack in
+`packs/` next to `info.toml`, or `--pack path/to/pack` for a pack anywhere
+else. The pack keeps its own `.rustlings-state.txt` and
+`.rustlings-progress.toml`, so it's ordered and tracked separately from the
+built-in exercises.
+*/
+
+use anyhow::{ensure, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::exercise::{ExerciseList, INFO_FILE};
+use crate::state::ProgressState;
+
+const PACKS_DIR: &str = "packs";
+
+// The root of a pack, given by name or by path.
+pub fn resolve(root: &Path, pack: &str) -> Result<PathBuf> {
+    let named = root.join(PACKS_DIR).join(pack);
+    let dir = if named.is_dir() {
+        named
+    } else {
+        PathBuf::from(pack)
+    };
+    ensure!(
+        dir.join(INFO_FILE).is_file(),
+        "There is no exercise pack `{pack}`, it needs an {INFO_FILE} in {} or {}",
+        root.join(PACKS_DIR).join(pack).display(),
+        dir.display()
+    );
+    Ok(dir)
+}
+
+// Prints the packs in `packs/` with their progress.
+pub fn show(root: &Path) -> Result<()> {
+    let dir = root.join(PACKS_DIR);
+    let mut names = Vec::new();
+    if dir.is_dir() {
+        let entries =
+            fs::read_dir(&dir).with_context(|| format!("Failed to read {}", dir.display()))?;
+        for entry in entries {
+            let entry = entry.with_context(|| format!("Failed to read {}", dir.display()))?;
+            if entry.path().join(INFO_FILE).is_file() {
+                names.push(entry.file_name().to_string_lossy().into_owned());
+            }
+        }
+    }
+    if names.is_empty() {
+        println!("No exercise packs in {}", dir.display());
+        return Ok(());
+    }
+
+    names.sort();
+    for name in names {
+        let list = ExerciseList::load(&dir.join(&name))?;
+        let state = ProgressState::load_or_fresh(&list)?;
+        println!(
+            "{name:<20} {:>3}/{:<3} done",
+            state.done.len(),
+            list.exercises.len()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crosscheck::WorkDir;
+
+    #[test]
+    fn resolves_packs_by_name_or_path() {
+        let work_dir = WorkDir::new().unwrap();
+        let root = work_dir.path();
+        let pack = root.join("packs/async");
+        fs::create_dir_all(&pack).unwrap();
+        fs::write(pack.join(INFO_FILE), "format_version = 1\n").unwrap();
+
+        assert_eq!(resolve(root, "async").unwrap(), pack);
+        let by_path = pack.to_string_lossy();
+        assert_eq!(resolve(Path::new("elsewhere"), &by_path).unwrap(), pack);
+        assert!(resolve(root, "missing").is_err());
+    }
+}
@@ -0,0 +1,144 @@
+/*
+Runs a child process with a deadline.
+
+`Command::output` waits forever, which is a problem for exercises that hang
+until they are solved, like `threads3` when a sender is never dropped. Here
+the output is read on separate threads while the child is polled, and the
+child is killed once the deadline has passed.
+
+On Unix the child gets its own process group and the whole group is killed,
+so nothing it spawned (like the test binary under `cargo test`) keeps running
+or keeps the output pipes open.
+*/
+
+use anyhow::{Context, Result};
+use std::io::Read;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+pub struct Run {
+    // `None` if the child was killed because it timed out.
+    pub status: Option<ExitStatus>,
+    pub stdout: String,
+    pub stderr: String,
+    pub duration: Duration,
+}
+
+impl Run {
+    pub fn success(&self) -> bool {
+        self.status.is_some_and(|status| status.success())
+    }
+
+    pub fn timed_out(&self) -> bool {
+        self.status.is_none()
+    }
+}
+
+#[cfg(unix)]
+fn isolate(command: &mut Command) {
+    use std::os::unix::process::CommandExt;
+    command.process_group(0);
+}
+
+#[cfg(not(unix))]
+fn isolate(_command: &mut Command) {}
+
+#[cfg(unix)]
+fn kill_tree(child: &mut Child) {
+    // The child leads its process group, so the group has the child's PID.
+    // It hasn't been waited for yet, so that PID can't have been reused.
+    if let Ok(group) = libc::pid_t::try_from(child.id()) {
+        unsafe { libc::kill(-group, libc::SIGKILL) };
+    }
+    let _ = child.kill();
+}
+
+#[cfg(not(unix))]
+fn kill_tree(child: &mut Child) {
+    let _ = child.kill();
+}
+
+fn read_all(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        let _ = pipe.read_to_end(&mut bytes);
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
+
+pub fn run(command: &mut Command, timeout: Duration) -> Result<Run> {
+    let start = Instant::now();
+    isolate(command);
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to run {command:?}"))?;
+
+    let stdout = read_all(child.stdout.take().expect("stdout is piped"));
+    let stderr = read_all(child.stderr.take().expect("stderr is piped"));
+
+    let status = loop {
+        if let Some(status) = child.try_wait().context("Failed to wait for a child")? {
+            break Some(status);
+        }
+        if start.elapsed() >= timeout {
+            kill_tree(&mut child);
+            let _ = child.wait();
+            break None;
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+
+    Ok(Run {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+        duration: start.elapsed(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kills_a_child_that_runs_too_long() {
+        let run = run(Command::new("sleep").arg("10"), Duration::from_millis(100)).unwrap();
+
+        assert!(run.timed_out());
+        assert!(run.duration < Duration::from_secs(5));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn kills_the_children_of_the_child() {
+        // The background `sleep` inherits the output pipes. If it survived,
+        // reading the output would wait for it.
+        let run = run(
+            Command::new("sh").args(["-c", "sleep 10 & sleep 10"]),
+            Duration::from_millis(100),
+        )
+        .unwrap();
+
+        assert!(run.timed_out());
+        assert!(run.duration < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn captures_the_output() {
+        let run = run(
+            Command::new("sh").args(["-c", "echo out; echo err >&2"]),
+            Duration::from_secs(10),
+        )
+        .unwrap();
+
+        assert!(run.success());
+        assert_eq!(run.stdout, "out\n");
+        assert_eq!(run.stderr, "err\n");
+    }
+}
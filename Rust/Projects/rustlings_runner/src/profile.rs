@@ -0,0 +1,210 @@
+/*
+Named learner profiles, for several learners sharing one checkout.
+
+The exercises crate only ever compiles `exercises/`, so the active profile
+always lives in place: its exercise files, `.rustlings-state.txt` and
+`.rustlings-progress.toml`. The other profiles are stored with the same layout
+under `profiles/<name>/`, and `.rustlings-profile` names the active one
+(`default` when it's missing).
+
+Switching first stores the active profile, then copies the other one into
+place. A new profile starts from the pristine exercises, and exercises that
+were added after a profile was stored start pristine as well.
+*/
+
+use anyhow::{bail, ensure, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::exercise::ExerciseList;
+use crate::progress::Progress;
+use crate::state::ProgressState;
+
+const PROFILE_FILE: &str = ".rustlings-profile";
+const PROFILES_DIR: &str = "profiles";
+const DEFAULT_PROFILE: &str = "default";
+
+fn validate_name(name: &str) -> Result<()> {
+    ensure!(
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'),
+        "Profile names can only contain letters, digits, `-` and `_`, not `{name}`"
+    );
+    Ok(())
+}
+
+fn stored(root: &Path, name: &str) -> PathBuf {
+    root.join(PROFILES_DIR).join(name)
+}
+
+pub fn active(root: &Path) -> Result<String> {
+    let path = root.join(PROFILE_FILE);
+    if !path.exists() {
+        return Ok(DEFAULT_PROFILE.to_string());
+    }
+    let contents =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let name = contents.trim().to_string();
+    validate_name(&name).with_context(|| format!("Invalid profile file {}", path.display()))?;
+    Ok(name)
+}
+
+// The active profile and the stored ones, sorted by name.
+pub fn names(root: &Path) -> Result<Vec<String>> {
+    let mut names = vec![active(root)?];
+    let dir = root.join(PROFILES_DIR);
+    if dir.is_dir() {
+        let entries =
+            fs::read_dir(&dir).with_context(|| format!("Failed to read {}", dir.display()))?;
+        for entry in entries {
+            let entry = entry.with_context(|| format!("Failed to read {}", dir.display()))?;
+            if entry.path().is_dir() {
+                names.push(entry.file_name().to_string_lossy().into_owned());
+            }
+        }
+    }
+    names.sort();
+    names.dedup();
+    Ok(names)
+}
+
+fn copy(from: &Path, to: &Path) -> Result<()> {
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    fs::copy(from, to)
+        .with_context(|| format!("Failed to copy {} to {}", from.display(), to.display()))?;
+    Ok(())
+}
+
+// Copies a whole profile from one root to another. The state files only exist
+// once the learner has verified something, so a missing one is removed on the
+// other side as well.
+fn transfer(list: &ExerciseList, from: &Path, to: &Path) -> Result<()> {
+    for exercise in &list.exercises {
+        let source = from.join(&exercise.path);
+        let source = if source.exists() {
+            source
+        } else {
+            list.root.join(&exercise.pristine)
+        };
+        copy(&source, &to.join(&exercise.path))?;
+    }
+
+    for path in [
+        ProgressState::path(Path::new("")),
+        Progress::path(Path::new("")),
+    ] {
+        let (source, target) = (from.join(&path), to.join(&path));
+        if source.exists() {
+            copy(&source, &target)?;
+        } else if target.exists() {
+            fs::remove_file(&target)
+                .with_context(|| format!("Failed to remove {}", target.display()))?;
+        }
+    }
+    Ok(())
+}
+
+// Stores a new profile with the pristine exercises and no progress.
+pub fn create(list: &ExerciseList, name: &str) -> Result<()> {
+    validate_name(name)?;
+    let dir = stored(&list.root, name);
+    ensure!(
+        name != active(&list.root)? && !dir.exists(),
+        "The profile `{name}` already exists"
+    );
+
+    for exercise in &list.exercises {
+        copy(
+            &list.root.join(&exercise.pristine),
+            &dir.join(&exercise.path),
+        )?;
+    }
+    Ok(())
+}
+
+pub fn switch(list: &ExerciseList, name: &str) -> Result<()> {
+    validate_name(name)?;
+    let current = active(&list.root)?;
+    if name == current {
+        bail!("`{name}` is already the active profile");
+    }
+    let dir = stored(&list.root, name);
+    ensure!(
+        dir.is_dir(),
+        "There is no profile `{name}`, create it with `profile new {name}`"
+    );
+
+    // Nothing is overwritten before the active profile is safely stored.
+    transfer(list, &list.root, &stored(&list.root, &current))?;
+    transfer(list, &dir, &list.root)?;
+    fs::remove_dir_all(&dir).with_context(|| format!("Failed to remove {}", dir.display()))?;
+
+    let path = list.root.join(PROFILE_FILE);
+    fs::write(&path, format!("{name}\n"))
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crosscheck::WorkDir;
+    use crate::exercise::{Exercise, Mode};
+
+    fn setup(root: &Path) -> ExerciseList {
+        let list = ExerciseList {
+            root: root.to_path_buf(),
+            exercises: ["intro1", "intro2"]
+                .iter()
+                .map(|name| Exercise::for_test("00_intro", name, Mode::Run))
+                .collect(),
+        };
+        for exercise in &list.exercises {
+            for path in [&exercise.path, &exercise.pristine] {
+                fs::create_dir_all(root.join(path).parent().unwrap()).unwrap();
+                fs::write(root.join(path), "// TODO\n").unwrap();
+            }
+        }
+        list
+    }
+
+    #[test]
+    fn switching_swaps_the_files_and_the_state() {
+        let work_dir = WorkDir::new().unwrap();
+        let root = work_dir.path();
+        let list = setup(root);
+        let intro1 = root.join("exercises/00_intro/intro1.rs");
+        fs::write(&intro1, "// solved\n").unwrap();
+        let mut state = ProgressState::fresh(&list).unwrap();
+        state.advance(&list);
+        state.write(&list).unwrap();
+
+        create(&list, "alice").unwrap();
+        switch(&list, "alice").unwrap();
+
+        assert_eq!(active(root).unwrap(), "alice");
+        assert_eq!(fs::read_to_string(&intro1).unwrap(), "// TODO\n");
+        assert!(!ProgressState::path(root).exists());
+        assert_eq!(names(root).unwrap(), ["alice", "default"]);
+
+        switch(&list, "default").unwrap();
+
+        assert_eq!(fs::read_to_string(&intro1).unwrap(), "// solved\n");
+        assert_eq!(ProgressState::load(&list).unwrap(), state);
+        assert!(stored(root, "alice").join("exercises").is_dir());
+    }
+
+    #[test]
+    fn rejects_bad_names() {
+        let work_dir = WorkDir::new().unwrap();
+        let list = setup(work_dir.path());
+
+        assert!(create(&list, "../alice").is_err());
+        assert!(create(&list, "default").is_err());
+        assert!(switch(&list, "bob").is_err());
+    }
+}
@@ -0,0 +1,212 @@
+/*
+Per-exercise progress that doesn't fit into `.rustlings-state.txt`.
+
+The state file has to stay readable by the upstream `rustlings` tool, so
+everything else we track is kept next to it in `.rustlings-progress.toml`:
+
+    [exercises.errors6]
+    hints_revealed = 2
+    first_seen = 1760770000
+    compile_attempts = 7
+    failed_test_runs = 3
+    last_outcome = "tests_failed"
+    last_verified = 1760770900
+
+The timestamps are Unix timestamps in seconds. `completed` is set when the
+exercise is marked as done, by `n` in watch mode or by `verify`, after which
+it isn't counted any more. Verifications that were rejected as tampered
+aren't attempts. The last outcome is always updated, for the dashboard of
+`serve`. Completing an exercise also schedules its first review, see
+`review.rs`.
+*/
+
+use anyhow::{ensure, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::exercise::ExerciseList;
+use crate::review::Review;
+use crate::verify::Outcome;
+
+const PROGRESS_FILE: &str = ".rustlings-progress.toml";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExerciseProgress {
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub hints_revealed: usize,
+    pub first_seen: Option<u64>,
+    pub completed: Option<u64>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub compile_attempts: usize,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub failed_test_runs: usize,
+    pub last_outcome: Option<Outcome>,
+    pub last_verified: Option<u64>,
+    // The spaced repetition schedule, once the exercise is done.
+    pub review: Option<Review>,
+}
+
+fn is_zero(count: &usize) -> bool {
+    *count == 0
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
+
+impl ExerciseProgress {
+    pub fn seen(&mut self, now: u64) {
+        self.first_seen.get_or_insert(now);
+    }
+
+    // Counts one verification of the exercise. Once it's completed, verifying
+    // it again only changes the last outcome.
+    pub fn record(&mut self, outcome: Outcome, now: u64) {
+        self.last_outcome = Some(outcome);
+        self.last_verified = Some(now);
+        if self.completed.is_some() {
+            return;
+        }
+        self.seen(now);
+        match outcome {
+            Outcome::Tampered => (),
+            Outcome::TestsFailed => {
+                self.compile_attempts += 1;
+                self.failed_test_runs += 1;
+            }
+            _ => self.compile_attempts += 1,
+        }
+    }
+
+    // Stops the counting once the exercise is marked as done, and schedules
+    // its first review.
+    pub fn complete(&mut self, now: u64) {
+        if self.completed.is_none() {
+            self.seen(now);
+            self.completed = Some(now);
+            self.review = Some(Review::first(now));
+        }
+    }
+
+    // How long the exercise took, or has taken so far if it isn't completed.
+    pub fn time_spent(&self, now: u64) -> u64 {
+        match self.first_seen {
+            Some(first_seen) => self.completed.unwrap_or(now).saturating_sub(first_seen),
+            None => 0,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Progress {
+    #[serde(default)]
+    pub exercises: BTreeMap<String, ExerciseProgress>,
+}
+
+impl Progress {
+    pub fn path(root: &Path) -> PathBuf {
+        root.join(PROGRESS_FILE)
+    }
+
+    // A missing file just means nothing was recorded yet.
+    pub fn load(list: &ExerciseList) -> Result<Self> {
+        let path = Self::path(&list.root);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let progress: Self = toml::from_str(&contents)
+            .with_context(|| format!("Invalid progress file {}", path.display()))?;
+        for name in progress.exercises.keys() {
+            ensure!(
+                list.find(name).is_ok(),
+                "{} tracks the unknown exercise `{name}`",
+                path.display()
+            );
+        }
+        Ok(progress)
+    }
+
+    // Written to a temporary file first, like the state file, so `serve`
+    // never reads half of it.
+    pub fn write(&self, list: &ExerciseList) -> Result<()> {
+        let path = Self::path(&list.root);
+        let contents = toml::to_string(self).context("Failed to serialize the progress")?;
+        let tmp = path.with_extension("toml.tmp");
+        fs::write(&tmp, contents).with_context(|| format!("Failed to write {}", tmp.display()))?;
+        fs::rename(&tmp, &path).with_context(|| format!("Failed to replace {}", path.display()))
+    }
+
+    pub fn exercise(&mut self, name: &str) -> &mut ExerciseProgress {
+        self.exercises.entry(name.to_string()).or_default()
+    }
+
+    // Records one verification of an exercise in the progress file.
+    pub fn record(list: &ExerciseList, name: &str, outcome: Outcome) -> Result<()> {
+        let mut progress = Self::load(list)?;
+        progress.exercise(name).record(outcome, now());
+        progress.write(list)
+    }
+
+    // Records that an exercise was marked as done in the progress file.
+    pub fn complete(list: &ExerciseList, name: &str) -> Result<()> {
+        let mut progress = Self::load(list)?;
+        progress.exercise(name).complete(now());
+        progress.write(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stops_counting_once_completed() {
+        let mut progress = ExerciseProgress::default();
+        progress.record(Outcome::CompileFailed, 100);
+        progress.record(Outcome::TestsFailed, 160);
+        progress.record(Outcome::Tampered, 200);
+        progress.record(Outcome::Passed, 400);
+        assert_eq!(progress.completed, None);
+        progress.record(Outcome::Passed, 420);
+        progress.complete(450);
+        progress.complete(600);
+        progress.record(Outcome::TestsFailed, 900);
+
+        assert_eq!(progress.first_seen, Some(100));
+        assert_eq!(progress.completed, Some(450));
+        assert_eq!(progress.compile_attempts, 4);
+        assert_eq!(progress.failed_test_runs, 1);
+        assert_eq!(progress.time_spent(1000), 350);
+        assert_eq!(progress.review, Some(Review::first(450)));
+        assert_eq!(progress.last_outcome, Some(Outcome::TestsFailed));
+        assert_eq!(progress.last_verified, Some(900));
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let mut progress = Progress::default();
+        progress.exercise("errors6").hints_revealed = 2;
+        progress
+            .exercise("errors6")
+            .record(Outcome::TestsFailed, 1760770000);
+
+        let contents = toml::to_string(&progress).unwrap();
+        assert_eq!(
+            contents,
+            "[exercises.errors6]\nhints_revealed = 2\nfirst_seen = 1760770000\n\
+             compile_attempts = 1\nfailed_test_runs = 1\nlast_outcome = \"tests_failed\"\n\
+             last_verified = 1760770000\n"
+        );
+        assert_eq!(toml::from_str::<Progress>(&contents).unwrap(), progress);
+    }
+}
@@ -0,0 +1,56 @@
+/*
+Restores an exercise to its unsolved state.
+
+The unsolved exercises are bundled in `pristine/`, which mirrors the layout of
+`exercises/`. Resetting copies the pristine file over the learner's file,
+removes the exercise from the done list and forgets the hints revealed for it,
+so the checkout can be handed to the next learner one exercise at a time.
+*/
+
+use anyhow::{Context, Result};
+use std::fs;
+
+use crate::exercise::ExerciseList;
+use crate::progress::Progress;
+use crate::state::ProgressState;
+
+pub fn reset(list: &ExerciseList, name: &str) -> Result<()> {
+    let exercise = list.find(name)?;
+
+    let pristine = list.root.join(&exercise.pristine);
+    let contents = fs::read_to_string(&pristine)
+        .with_context(|| format!("Failed to read {}", pristine.display()))?;
+    let path = list.root.join(&exercise.path);
+    fs::write(&path, contents).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    let mut state = ProgressState::load_or_fresh(list)?;
+    state.unmark_done(&exercise.name);
+    state.write(list)?;
+
+    let mut progress = Progress::load(list)?;
+    if progress.exercises.remove(&exercise.name).is_some() {
+        progress.write(list)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn every_exercise_has_a_pristine_copy() {
+        let list = ExerciseList::checked_in();
+        let root = &list.root;
+
+        let missing: Vec<&str> = list
+            .exercises
+            .iter()
+            .filter(|exercise| !root.join(&exercise.pristine).is_file())
+            .map(|exercise| exercise.name.as_str())
+            .collect();
+
+        assert!(missing.is_empty(), "No pristine copy of {missing:?}");
+    }
+}
@@ -0,0 +1,321 @@
+/*
+Spaced repetition of the done exercises, scheduled with SM-2.
+
+An exercise is first due for review a day after it was completed. `review`
+hands out the most overdue one as a fresh copy of the pristine exercise in
+`.rustlings-review/`, away from `exercises/`, so solving it again doesn't
+touch the learner's file or `.rustlings-state.txt`.
+
+    rustlings review             hand out the next due exercise
+    rustlings review --check     check the copy and reschedule it
+    rustlings review --give-up   reschedule it as forgotten
+
+A check goes through the steps of `verify`, including Clippy and the expected
+output for the exercises that have them, in a throwaway project like the one of
+`sandbox`. A passing check grades the review by the number of failed checks
+before it:
+5 without any, 4 after one and 3 after more. Giving up grades it 1. As in
+SM-2, a grade below 3 starts the intervals over at one day, and otherwise the
+interval grows to 6 days and then by the exercise's ease factor, which the
+grade adjusts. The schedule is kept in `.rustlings-progress.toml`:
+
+    [exercises.lifetimes1.review]
+    due = 1761370000
+    interval = 6
+    ease = 250
+    repetitions = 2
+
+`interval` is in days and `ease` in hundredths.
+*/
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::crosscheck::split_tests;
+use crate::exercise::{Exercise, ExerciseList, Mode};
+use crate::progress::{self, Progress};
+use crate::sandbox::Sandbox;
+use crate::state::ProgressState;
+use crate::verify::Outcome;
+
+const REVIEW_DIR: &str = ".rustlings-review";
+const DAY: u64 = 24 * 60 * 60;
+const INITIAL_EASE: u32 = 250;
+const MIN_EASE: u32 = 130;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Review {
+    pub due: u64,
+    pub interval: u64,
+    pub ease: u32,
+    pub repetitions: u32,
+    // Of the review that's in progress.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub failed_checks: u32,
+}
+
+fn is_zero(count: &u32) -> bool {
+    *count == 0
+}
+
+impl Review {
+    // Solving the exercise the first time counts as the first repetition.
+    pub fn first(completed: u64) -> Self {
+        Self {
+            due: completed + DAY,
+            interval: 1,
+            ease: INITIAL_EASE,
+            repetitions: 1,
+            failed_checks: 0,
+        }
+    }
+
+    // Reschedules with an SM-2 grade from 0 (forgotten) to 5 (perfect).
+    pub fn grade(&mut self, quality: u32, now: u64) {
+        let quality = quality.min(5);
+        if quality < 3 {
+            self.repetitions = 0;
+            self.interval = 1;
+        } else {
+            self.interval = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (self.interval * u64::from(self.ease)).div_ceil(100),
+            };
+            self.repetitions += 1;
+        }
+        // EF' = EF + 0.1 - (5 - q) * (0.08 + (5 - q) * 0.02)
+        let missed = 5 - quality;
+        let penalty = missed * (8 + missed * 2);
+        self.ease = (self.ease + 10).saturating_sub(penalty).max(MIN_EASE);
+        self.due = now + self.interval * DAY;
+        self.failed_checks = 0;
+    }
+}
+
+fn scratch_path(list: &ExerciseList, exercise: &Exercise) -> PathBuf {
+    list.root
+        .join(REVIEW_DIR)
+        .join(format!("{}.rs", exercise.name))
+}
+
+// Schedules the done exercises that were completed before reviews existed, or
+// whose progress was lost, as if they were completed now.
+fn schedule_done(list: &ExerciseList, state: &ProgressState, progress: &mut Progress, now: u64) {
+    for name in &state.done {
+        let exercise = progress.exercise(name);
+        if exercise.review.is_none() {
+            exercise.review = Some(Review::first(exercise.completed.unwrap_or(now)));
+        }
+    }
+    // Exercises that were reset aren't done any more.
+    for exercise in &list.exercises {
+        if !state.is_done(&exercise.name) {
+            if let Some(progress) = progress.exercises.get_mut(&exercise.name) {
+                progress.review = None;
+            }
+        }
+    }
+}
+
+// The exercises that are due, the most overdue first.
+fn due<'a>(list: &'a ExerciseList, progress: &Progress, now: u64) -> Vec<(&'a Exercise, u64)> {
+    let mut due: Vec<(&Exercise, u64)> = list
+        .exercises
+        .iter()
+        .filter_map(|exercise| {
+            let review = progress.exercises.get(&exercise.name)?.review.as_ref()?;
+            Some((exercise, review.due))
+        })
+        .filter(|&(_, due)| due <= now)
+        .collect();
+    due.sort_by_key(|&(_, due)| due);
+    due
+}
+
+// The exercise whose copy is in the scratch directory.
+fn in_progress(list: &ExerciseList) -> Option<&Exercise> {
+    list.exercises
+        .iter()
+        .find(|exercise| scratch_path(list, exercise).exists())
+}
+
+fn days(seconds: u64) -> String {
+    match seconds / DAY {
+        0 => "less than a day".to_string(),
+        1 => "1 day".to_string(),
+        days => format!("{days} days"),
+    }
+}
+
+fn load(list: &ExerciseList, now: u64) -> Result<Progress> {
+    let state = ProgressState::load_or_fresh(list)?;
+    let mut progress = Progress::load(list)?;
+    schedule_done(list, &state, &mut progress, now);
+    Ok(progress)
+}
+
+// Hands out the next due exercise, unless one is already being reviewed.
+pub fn start(list: &ExerciseList) -> Result<()> {
+    let now = progress::now();
+    let progress = load(list, now)?;
+    progress.write(list)?;
+
+    if let Some(exercise) = in_progress(list) {
+        println!(
+            "You are reviewing `{}` in {}",
+            exercise.name,
+            scratch_path(list, exercise).display()
+        );
+        println!("Run `review --check` once it's solved, or `review --give-up`.");
+        return Ok(());
+    }
+
+    let due = due(list, &progress, now);
+    let Some(&(exercise, due_at)) = due.first() else {
+        let next = list
+            .exercises
+            .iter()
+            .filter_map(|exercise| {
+                let review = progress.exercises.get(&exercise.name)?.review.as_ref()?;
+                Some((exercise, review.due))
+            })
+            .min_by_key(|&(_, due)| due);
+        match next {
+            Some((exercise, due)) => println!(
+                "Nothing to review. `{}` is next, in {}.",
+                exercise.name,
+                days(due - now)
+            ),
+            None => println!("Nothing to review. Exercises are scheduled once they are done."),
+        }
+        return Ok(());
+    };
+
+    let path = scratch_path(list, exercise);
+    let parent = path.parent().unwrap();
+    fs::create_dir_all(parent).with_context(|| format!("Failed to create {}", parent.display()))?;
+    let pristine = list.root.join(&exercise.pristine);
+    fs::copy(&pristine, &path).with_context(|| {
+        format!(
+            "Failed to copy {} to {}",
+            pristine.display(),
+            path.display()
+        )
+    })?;
+
+    let overdue = match now - due_at {
+        0..DAY => "due today".to_string(),
+        late => format!("overdue by {}", days(late)),
+    };
+    println!(
+        "Review `{}` ({overdue}, {} more due)",
+        exercise.name,
+        due.len() - 1
+    );
+    println!("Solve it again in {}", path.display());
+    println!("Then run `review --check`, or `review --give-up` if you can't remember it.");
+    Ok(())
+}
+
+// Checks the reviewed copy with the pristine tests, so editing them doesn't
+// help.
+fn check_copy(list: &ExerciseList, exercise: &Exercise) -> Result<(Outcome, String)> {
+    let read = |path: &PathBuf| {
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+    };
+    let mut source = read(&scratch_path(list, exercise))?;
+    if exercise.mode == Mode::Test {
+        let pristine = read(&list.root.join(&exercise.pristine))?;
+        let tests = split_tests(&pristine).1.unwrap_or_default();
+        source = format!("{}\n{tests}", split_tests(&source).0);
+    }
+    let dir = list.root.join(REVIEW_DIR).join(&exercise.name);
+    let sandbox = Sandbox::create(list, dir, &source)?;
+    sandbox.check(list, exercise)
+}
+
+// Checks the exercise that's being reviewed, or gives up on it, and
+// reschedules it once it's finished. Returns whether it passed.
+pub fn finish(list: &ExerciseList, give_up: bool) -> Result<bool> {
+    let Some(exercise) = in_progress(list) else {
+        bail!("No exercise is being reviewed, start with `review`");
+    };
+    let now = progress::now();
+    let mut progress = load(list, now)?;
+    let review = progress
+        .exercise(&exercise.name)
+        .review
+        .get_or_insert_with(|| Review::first(now));
+
+    let passed = if give_up {
+        review.grade(1, now);
+        false
+    } else {
+        let (outcome, output) = check_copy(list, exercise)?;
+        if !outcome.passed() {
+            review.failed_checks += 1;
+            print!("{output}");
+            println!("\n`{}` {}. Keep going!", exercise.name, outcome.describe());
+            progress.write(list)?;
+            return Ok(false);
+        }
+        review.grade(5 - review.failed_checks.min(2), now);
+        true
+    };
+
+    let next = days(review.interval * DAY);
+    progress.write(list)?;
+    let path = scratch_path(list, exercise);
+    fs::remove_file(&path).with_context(|| format!("Failed to remove {}", path.display()))?;
+    if passed {
+        println!("`{}` passed! The next review is in {next}.", exercise.name);
+    } else {
+        println!("`{}` comes back for review in {next}.", exercise.name);
+    }
+    Ok(passed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grows_the_interval_like_sm2() {
+        let mut review = Review::first(0);
+        assert_eq!(review.due, DAY);
+
+        review.grade(5, DAY);
+        assert_eq!(
+            (review.interval, review.ease, review.repetitions),
+            (6, 260, 2)
+        );
+        assert_eq!(review.due, 7 * DAY);
+
+        review.grade(4, 7 * DAY);
+        assert_eq!((review.interval, review.ease), (16, 260));
+
+        review.grade(3, 23 * DAY);
+        assert_eq!((review.interval, review.ease), (42, 246));
+
+        review.failed_checks = 2;
+        review.grade(1, 65 * DAY);
+        assert_eq!((review.interval, review.repetitions), (1, 0));
+        assert_eq!((review.ease, review.failed_checks), (192, 0));
+
+        review.grade(5, 66 * DAY);
+        assert_eq!(review.interval, 1);
+    }
+
+    #[test]
+    fn ease_never_drops_below_the_minimum() {
+        let mut review = Review::first(0);
+        for _ in 0..10 {
+            review.grade(0, 0);
+        }
+        assert_eq!(review.ease, MIN_EASE);
+    }
+}
@@ -0,0 +1,263 @@
+/*
+A throwaway cargo project for experimenting with an exercise.
+
+    rustlings sandbox move_semantics2
+
+copies the exercise to `.rustlings-sandbox/<name>/src/main.rs` and re-runs its
+`main` every time it's saved, like a REPL for the "You can optionally
+experiment here" part. The project's `Cargo.toml` is the `[package]` part of
+the exercises crate's, with its edition, features and lints, and Clippy is
+run first for the exercises that are checked with it.
+
+The exercise file and `.rustlings-state.txt` are never touched, and the
+project is removed when the sandbox is quit with `q`. `review` checks its
+copies in the same kind of project, with the steps of `verify`.
+*/
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use crate::exercise::{Exercise, ExerciseList, Mode};
+use crate::process;
+use crate::verify::{self, cargo, Outcome, DEFAULT_TIMEOUT};
+use crate::watch::{self, Event};
+
+const SANDBOX_DIR: &str = ".rustlings-sandbox";
+const PACKAGE_NAME: &str = "sandbox";
+const BUILD_TIMEOUT: Duration = Duration::from_secs(60);
+
+// The exercises crate's manifest without its bins, for a crate of its own.
+fn manifest(exercises: &str) -> Result<String> {
+    let start = exercises
+        .find("[package]")
+        .context("Cargo.toml has no `[package]` table")?;
+    let mut manifest = String::new();
+    let mut renamed = false;
+    for line in exercises[start..].lines() {
+        if !renamed && line.starts_with("name = ") {
+            manifest.push_str(&format!("name = \"{PACKAGE_NAME}\"\n"));
+            renamed = true;
+        } else {
+            manifest.push_str(line);
+            manifest.push('\n');
+        }
+    }
+    // Its own workspace, wherever the sandbox is.
+    if !manifest.contains("\n[workspace]") {
+        manifest.push_str("\n[workspace]\n");
+    }
+    Ok(manifest)
+}
+
+// A project with `source` as its `main.rs`, removed when dropped.
+pub struct Sandbox(PathBuf);
+
+impl Sandbox {
+    pub fn create(list: &ExerciseList, dir: PathBuf, source: &str) -> Result<Self> {
+        // Left behind by a sandbox that was killed.
+        if dir.exists() {
+            fs::remove_dir_all(&dir)
+                .with_context(|| format!("Failed to remove {}", dir.display()))?;
+        }
+        let sandbox = Self(dir);
+        let src = sandbox.0.join("src");
+        fs::create_dir_all(&src).with_context(|| format!("Failed to create {}", src.display()))?;
+
+        let path = list.root.join("Cargo.toml");
+        let exercises = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let path = sandbox.0.join("Cargo.toml");
+        fs::write(&path, manifest(&exercises)?)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        let path = sandbox.main();
+        fs::write(&path, source).with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(sandbox)
+    }
+
+    fn main(&self) -> PathBuf {
+        self.0.join("src").join("main.rs")
+    }
+
+    fn cargo(&self, args: &[&str]) -> Command {
+        let mut cargo = cargo();
+        cargo
+            .args(args)
+            .arg("--quiet")
+            .arg("--manifest-path")
+            .arg(self.0.join("Cargo.toml"))
+            // Not the shared `CARGO_TARGET_DIR`, so the binary is found.
+            .arg("--target-dir")
+            .arg(self.0.join("target"));
+        cargo
+    }
+
+    fn binary(&self) -> PathBuf {
+        self.0.join("target").join("debug").join(PACKAGE_NAME)
+    }
+
+    // Builds the project, and lints it for the exercises that are checked with
+    // Clippy. Returns the failed step with the compiler output.
+    fn build(&self, exercise: &Exercise, output: &mut String) -> Result<Option<Outcome>> {
+        let build = process::run(&mut self.cargo(&["build"]), BUILD_TIMEOUT)?;
+        output.push_str(&build.stderr);
+        if !build.success() {
+            return Ok(Some(Outcome::CompileFailed));
+        }
+        if exercise.strict_clippy {
+            let mut clippy = self.cargo(&["clippy"]);
+            clippy.args(["--", "-D", "warnings"]);
+            let clippy = process::run(&mut clippy, BUILD_TIMEOUT)?;
+            output.push_str(&clippy.stderr);
+            if !clippy.success() {
+                return Ok(Some(Outcome::ClippyFailed));
+            }
+        }
+        Ok(None)
+    }
+
+    // Checks the project like `verify` checks the exercise, except for the
+    // protected code.
+    pub fn check(&self, list: &ExerciseList, exercise: &Exercise) -> Result<(Outcome, String)> {
+        let mut output = String::new();
+        if let Some(failed) = self.build(exercise, &mut output)? {
+            return Ok((failed, output));
+        }
+
+        let timeout = exercise.timeout.unwrap_or(DEFAULT_TIMEOUT);
+        let run = process::run(&mut Command::new(self.binary()), timeout)?;
+        output.push_str(&run.stderr);
+        output.push_str(&run.stdout);
+        if run.timed_out() {
+            return Ok((verify::timed_out(&list.root, exercise), output));
+        }
+        if !run.success() {
+            return Ok((Outcome::RunFailed, output));
+        }
+        if let Some(expected) = &exercise.expected_output {
+            if let Some(mismatch) = expected.check(&run.stdout)? {
+                output.push_str(&mismatch);
+                return Ok((Outcome::WrongOutput, output));
+            }
+        }
+
+        if exercise.mode == Mode::Test {
+            let build = process::run(&mut self.cargo(&["test", "--no-run"]), BUILD_TIMEOUT)?;
+            output.push_str(&build.stderr);
+            if !build.success() {
+                return Ok((Outcome::TestsFailed, output));
+            }
+            let tests = process::run(&mut self.cargo(&["test"]), timeout)?;
+            output.push_str(&tests.stderr);
+            output.push_str(&tests.stdout);
+            if tests.timed_out() {
+                return Ok((verify::timed_out(&list.root, exercise), output));
+            }
+            if !tests.success() {
+                return Ok((Outcome::TestsFailed, output));
+            }
+        }
+        Ok((Outcome::Passed, output))
+    }
+}
+
+impl Drop for Sandbox {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+        // Only succeeds once no other sandbox is open.
+        if let Some(parent) = self.0.parent() {
+            let _ = fs::remove_dir(parent);
+        }
+    }
+}
+
+fn is_main(sandbox: &Sandbox, changed: &Path) -> bool {
+    match (sandbox.main().canonicalize(), changed.canonicalize()) {
+        (Ok(main), Ok(changed)) => main == changed,
+        _ => false,
+    }
+}
+
+// Builds the sandbox and runs `main`, printing everything on a clear screen.
+fn run(sandbox: &Sandbox, exercise: &Exercise) -> Result<()> {
+    print!("\x1b[2J\x1b[H");
+    println!("Running `{}`...\n", sandbox.main().display());
+
+    let mut output = String::new();
+    let failed = sandbox.build(exercise, &mut output)?;
+    print!("{output}");
+    if failed.is_some() {
+        println!("\nWaiting for you to fix it and save...");
+        return Ok(());
+    }
+
+    let timeout = exercise.timeout.unwrap_or(DEFAULT_TIMEOUT);
+    let run = process::run(&mut Command::new(sandbox.binary()), timeout)?;
+    print!("{}{}", run.stderr, run.stdout);
+    match run.status {
+        Some(status) if status.success() => println!("\n`main` finished."),
+        Some(status) => println!("\n`main` failed ({status})."),
+        None => println!("\n`main` was stopped after {}s.", timeout.as_secs()),
+    }
+    println!("Save to run it again, or enter `q` to quit and delete the sandbox.");
+    Ok(())
+}
+
+pub fn sandbox(list: &ExerciseList, name: &str) -> Result<()> {
+    let exercise = list.find(name)?;
+    let path = list.root.join(&exercise.path);
+    let source =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let dir = list.root.join(SANDBOX_DIR).join(&exercise.name);
+    let sandbox = Sandbox::create(list, dir, &source)?;
+    let (_watcher, rx) = watch::events(&sandbox.0.join("src"))?;
+    run(&sandbox, exercise)?;
+
+    let mut queued = None;
+    loop {
+        let event = match queued.take() {
+            Some(event) => event,
+            None => match rx.recv() {
+                Ok(event) => event,
+                Err(_) => break,
+            },
+        };
+        match event {
+            Event::FileChanged(path) => {
+                if is_main(&sandbox, &path) {
+                    queued = watch::drain(&rx);
+                    run(&sandbox, exercise)?;
+                }
+            }
+            Event::Input(input) => match input.as_str() {
+                "q" => break,
+                "r" => run(&sandbox, exercise)?,
+                _ => println!("Enter `r` to run `main` again or `q` to quit"),
+            },
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_package_settings_without_the_bins() {
+        let exercises =
+            "bin = [\n  { name = \"intro1\", path = \"exercises/00_intro/intro1.rs\" },\n]\n\n\
+             [package]\nname = \"exercises\"\nedition = \"2021\"\n\n\
+             [lints.clippy]\nneedless_return = \"deny\"\n";
+
+        assert_eq!(
+            manifest(exercises).unwrap(),
+            "[package]\nname = \"sandbox\"\nedition = \"2021\"\n\n\
+             [lints.clippy]\nneedless_return = \"deny\"\n\n[workspace]\n"
+        );
+        assert!(manifest("[workspace]\n").is_err());
+    }
+}
@@ -0,0 +1,282 @@
+/*
+Scaffolds a new exercise, for in-house exercises next to the upstream ones.
+
+    rustlings new-exercise 24_our_patterns builders1 --book §5.3
+
+creates `exercises/`, `pristine/` and `solutions/` files with the usual layout,
+declares the exercise in `info.toml` right after the last exercise of its
+topic (or at the end for a new topic), regenerates the `bin` list of
+`Cargo.toml` from it and lists the exercise in the topic's `README.md`.
+Everything left to write is marked with `TODO`.
+*/
+
+use anyhow::{ensure, Context, Result};
+use regex::Regex;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use crate::cargo_toml;
+use crate::exercise::{parse_info, ExerciseList, Mode, INFO_FILE};
+
+const EXERCISES_HEADING: &str = "## Exercises";
+
+pub struct NewExercise<'a> {
+    pub dir: &'a str,
+    pub name: &'a str,
+    pub mode: Mode,
+    pub book: Option<&'a str>,
+}
+
+fn skeleton(mode: Mode) -> &'static str {
+    match mode {
+        Mode::Test => {
+            "// TODO: Explain what the learner has to do.
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    // TODO: Test the exercise, and leave a `TODO` in the tests if the learner
+    // has to write some of them.
+    #[test]
+    fn todo() {
+        todo!(\"Test the exercise\");
+    }
+}
+"
+        }
+        Mode::Run => {
+            "fn main() {
+    // TODO: Explain what the learner has to do.
+}
+"
+        }
+    }
+}
+
+fn info_entry(new: &NewExercise) -> String {
+    let mut entry = format!(
+        "[[exercises]]\nname = \"{}\"\ndir = \"{}\"\n",
+        new.name, new.dir
+    );
+    if let Some(book) = new.book {
+        let _ = writeln!(entry, "book = \"{book}\"");
+    }
+    let mode = match new.mode {
+        Mode::Test => "test",
+        Mode::Run => "run",
+    };
+    let _ = writeln!(entry, "mode = \"{mode}\"");
+    entry.push_str("hints = [\n    \"TODO: The concept behind the exercise.\",\n]\n");
+    entry
+}
+
+// Inserts an `[[exercises]]` entry after the one named `after`, or at the end.
+fn insert_entry(info: &str, after: Option<&str>, entry: &str) -> Result<String> {
+    let end = match after {
+        Some(after) => {
+            let start = info
+                .find(&format!("\nname = \"{after}\"\n"))
+                .with_context(|| format!("`{after}` isn't declared in {INFO_FILE}"))?;
+            info[start + 1..]
+                .find("\n[[exercises]]")
+                .map(|next| start + 1 + next + 1)
+        }
+        None => None,
+    };
+
+    let mut info = info.to_string();
+    match end {
+        // The entries are separated by a blank line, which is already there.
+        Some(end) => info.insert_str(end, &format!("{entry}\n")),
+        None => {
+            if !info.ends_with('\n') {
+                info.push('\n');
+            }
+            info.push('\n');
+            info.push_str(entry);
+        }
+    }
+    Ok(info)
+}
+
+// Lists the exercise under the `## Exercises` heading of the topic's README,
+// creating both if needed.
+fn update_readme(readme: Option<&str>, new: &NewExercise) -> String {
+    let line = format!("- `{}`: TODO\n", new.name);
+    let Some(readme) = readme else {
+        let topic = new.dir.split_once('_').map_or(new.dir, |(_, topic)| topic);
+        let mut title = topic.replace('_', " ");
+        title[..1].make_ascii_uppercase();
+        return format!("# {title}\n\nTODO: Introduce the topic.\n\n{EXERCISES_HEADING}\n\n{line}");
+    };
+
+    let mut readme = readme.to_string();
+    if !readme.ends_with('\n') {
+        readme.push('\n');
+    }
+    match readme.find(&format!("{EXERCISES_HEADING}\n")) {
+        Some(start) => {
+            // The list ends at the next heading.
+            let body = start + EXERCISES_HEADING.len() + 1;
+            let end = readme[body..]
+                .find("\n#")
+                .map_or(readme.len(), |next| body + next + 1);
+            let insert_at = readme[..end].trim_end().len() + 1;
+            readme.insert_str(insert_at, &line);
+        }
+        None => {
+            let _ = write!(readme, "\n{EXERCISES_HEADING}\n\n{line}");
+        }
+    }
+    readme
+}
+
+fn write_new(root: &Path, path: &Path, contents: &str) -> Result<()> {
+    let path = root.join(path);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    fs::write(&path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+pub fn scaffold(list: &ExerciseList, new: &NewExercise) -> Result<()> {
+    let topic = Regex::new(r"^\d\d_[a-z0-9_]+$").unwrap();
+    ensure!(
+        topic.is_match(new.dir),
+        "The topic has to look like `24_our_patterns`, not `{}`",
+        new.dir
+    );
+    let name = Regex::new(r"^[a-z][a-z0-9_]*$").unwrap();
+    ensure!(
+        name.is_match(new.name) && !new.name.ends_with("_sol"),
+        "`{}` isn't a valid exercise name",
+        new.name
+    );
+    ensure!(
+        list.find(new.name).is_err(),
+        "The exercise `{}` already exists",
+        new.name
+    );
+
+    let info_path = list.root.join(INFO_FILE);
+    let info = fs::read_to_string(&info_path)
+        .with_context(|| format!("Failed to read {}", info_path.display()))?;
+    let after = list
+        .exercises
+        .iter()
+        .rev()
+        .find(|exercise| exercise.dir == new.dir)
+        .map(|exercise| exercise.name.as_str());
+    let info = insert_entry(&info, after, &info_entry(new))?;
+
+    // Parse the updated `info.toml` before anything is written, so a broken
+    // entry can't end up in the checkout.
+    let exercise = parse_info(&info)
+        .with_context(|| format!("The new {INFO_FILE} would be invalid"))?
+        .into_iter()
+        .find(|exercise| exercise.name == new.name)
+        .context("The new exercise is missing from the updated info.toml")?;
+    for path in [&exercise.path, &exercise.pristine, &exercise.solution] {
+        ensure!(
+            !list.root.join(path).exists(),
+            "{} already exists",
+            path.display()
+        );
+    }
+    for path in [&exercise.path, &exercise.pristine, &exercise.solution] {
+        write_new(&list.root, path, skeleton(new.mode))?;
+    }
+    fs::write(&info_path, &info)
+        .with_context(|| format!("Failed to write {}", info_path.display()))?;
+
+    let list = ExerciseList::load(&list.root)?;
+    cargo_toml::update(&list, false)?;
+
+    let readme_path = list.root.join("exercises").join(new.dir).join("README.md");
+    let readme = if readme_path.exists() {
+        Some(
+            fs::read_to_string(&readme_path)
+                .with_context(|| format!("Failed to read {}", readme_path.display()))?,
+        )
+    } else {
+        None
+    };
+    fs::write(&readme_path, update_readme(readme.as_deref(), new))
+        .with_context(|| format!("Failed to write {}", readme_path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INFO: &str = "format_version = 1
+
+[[exercises]]
+name = \"vecs1\"
+dir = \"05_vecs\"
+mode = \"test\"
+hints = [\"\"]
+
+[[exercises]]
+name = \"quiz1\"
+dir = \"quizzes\"
+mode = \"test\"
+hints = [\"\"]
+";
+
+    fn new(dir: &'static str) -> NewExercise<'static> {
+        NewExercise {
+            dir,
+            name: "vecs3",
+            mode: Mode::Run,
+            book: Some("§8.1"),
+        }
+    }
+
+    #[test]
+    fn inserts_after_the_last_exercise_of_the_topic() {
+        let info = insert_entry(INFO, Some("vecs1"), &info_entry(&new("05_vecs"))).unwrap();
+
+        let names: Vec<String> = parse_info(&info)
+            .unwrap()
+            .into_iter()
+            .map(|exercise| exercise.name)
+            .collect();
+        assert_eq!(names, ["vecs1", "vecs3", "quiz1"]);
+        assert!(info.contains(
+            "hints = [\"\"]\n\n[[exercises]]\nname = \"vecs3\"\ndir = \"05_vecs\"\n\
+             book = \"§8.1\"\nmode = \"run\"\n"
+        ));
+        assert!(info.contains("]\n\n[[exercises]]\nname = \"quiz1\""));
+    }
+
+    #[test]
+    fn appends_a_new_topic() {
+        let info = insert_entry(INFO, None, &info_entry(&new("24_ours"))).unwrap();
+
+        assert!(info.starts_with(INFO));
+        assert!(info[INFO.len()..].starts_with("\n[[exercises]]\nname = \"vecs3\""));
+    }
+
+    #[test]
+    fn lists_the_exercise_in_the_readme() {
+        let readme =
+            "# Vectors\n\nIntro.\n\n## Exercises\n\n- `vecs2`: Mine\n\n## Further information\n";
+
+        assert_eq!(
+            update_readme(Some(readme), &new("05_vecs")),
+            "# Vectors\n\nIntro.\n\n## Exercises\n\n- `vecs2`: Mine\n- `vecs3`: TODO\n\n\
+             ## Further information\n"
+        );
+        assert_eq!(
+            update_readme(Some("# Vectors\n"), &new("05_vecs")),
+            "# Vectors\n\n## Exercises\n\n- `vecs3`: TODO\n"
+        );
+        assert!(update_readme(None, &new("24_ours")).starts_with("# Ours\n"));
+    }
+}
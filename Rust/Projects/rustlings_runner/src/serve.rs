@@ -0,0 +1,205 @@
+/*
+A read-only progress dashboard on localhost, for showing a learner's status on
+a screen share.
+
+    rustlings serve --port 7878
+
+Every request reads `.rustlings-state.txt` and `.rustlings-progress.toml`
+again, so the page is as current as the CLI that's running next to it, and
+it reloads itself every few seconds. It shows the progress per topic with its
+chapter from the table in `exercises/README.md`, and every exercise with the
+outcome of its last verification.
+
+The server only listens on 127.0.0.1. It's behind the `serve` feature, which is
+on by default.
+*/
+
+use anyhow::{anyhow, Result};
+use std::fmt::Write;
+use tiny_http::{Header, Response, Server};
+
+use crate::book::Book;
+use crate::exercise::ExerciseList;
+use crate::profile;
+use crate::progress::{self, Progress};
+use crate::state::ProgressState;
+use crate::stats::format_time;
+use crate::topics;
+
+const REFRESH_SECONDS: u32 = 5;
+
+const STYLE: &str = "body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; margin-bottom: 2em; }
+th, td { padding: 0.2em 0.8em; text-align: left; border-bottom: 1px solid #ddd; }
+.done { color: #2a7a2a; }
+.current { font-weight: bold; }
+.failed { color: #b02a2a; }";
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render(
+    list: &ExerciseList,
+    state: &ProgressState,
+    progress: &Progress,
+    book: &Book,
+    profile: &str,
+    now: u64,
+) -> String {
+    let mut html = String::new();
+    let _ = write!(
+        html,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta http-equiv=\"refresh\" content=\"{REFRESH_SECONDS}\">\n\
+         <title>Rustlings progress</title>\n<style>\n{STYLE}\n</style>\n</head>\n<body>\n"
+    );
+    let _ = writeln!(
+        html,
+        "<h1>Rustlings progress</h1>\n<p>Profile <b>{}</b>: {}/{} exercises done, \
+         working on <b>{}</b>.</p>",
+        escape(profile),
+        state.done.len(),
+        list.exercises.len(),
+        escape(&state.current)
+    );
+
+    html.push_str("<h2>Topics</h2>\n<table>\n");
+    html.push_str("<tr><th>Topic</th><th>Book chapter</th><th>Done</th><th></th></tr>\n");
+    // Every quiz is a topic of its own, like in the topic map.
+    let mut rows: Vec<(&str, usize, usize)> = Vec::new();
+    for exercise in &list.exercises {
+        let topic = topics::node_name(exercise);
+        if rows.last().is_none_or(|(name, ..)| *name != topic) {
+            rows.push((topic, 0, 0));
+        }
+        let row = rows.last_mut().unwrap();
+        row.1 += usize::from(state.is_done(&exercise.name));
+        row.2 += 1;
+    }
+    for (topic, done, total) in rows {
+        let name = topic.split_once('_').map_or(topic, |(_, name)| name);
+        let chapter = book.chapter(name).unwrap_or("");
+        let class = if done == total { " class=\"done\"" } else { "" };
+        let _ = writeln!(
+            html,
+            "<tr{class}><td>{}</td><td>{}</td><td>{done}/{total}</td>\
+             <td><progress value=\"{done}\" max=\"{total}\"></progress></td></tr>",
+            escape(topic),
+            escape(chapter)
+        );
+    }
+    html.push_str("</table>\n");
+
+    html.push_str("<h2>Exercises</h2>\n<table>\n");
+    html.push_str("<tr><th></th><th>Exercise</th><th>Status</th><th>Last result</th></tr>\n");
+    for (n, exercise) in list.exercises.iter().enumerate() {
+        let (class, status) = if exercise.name == state.current {
+            ("current", "current")
+        } else if state.is_done(&exercise.name) {
+            ("done", "done")
+        } else {
+            ("", "")
+        };
+        let last = progress.exercises.get(&exercise.name);
+        let result = match last.and_then(|last| last.last_outcome.zip(last.last_verified)) {
+            Some((outcome, at)) => format!(
+                "<span class=\"{}\">{}</span> ({} ago)",
+                if outcome.passed() { "done" } else { "failed" },
+                outcome.describe(),
+                format_time(now.saturating_sub(at))
+            ),
+            None => String::new(),
+        };
+        let _ = writeln!(
+            html,
+            "<tr class=\"{class}\"><td>{}</td><td>{}</td><td>{status}</td><td>{result}</td></tr>",
+            n + 1,
+            escape(&exercise.name)
+        );
+    }
+    html.push_str("</table>\n</body>\n</html>\n");
+    html
+}
+
+fn page(list: &ExerciseList) -> Result<String> {
+    let state = ProgressState::load_or_fresh(list)?;
+    let progress = Progress::load(list)?;
+    // Packs don't necessarily have a chapter table.
+    let book = Book::load(&list.root).unwrap_or_default();
+    let profile = profile::active(&list.root)?;
+    Ok(render(
+        list,
+        &state,
+        &progress,
+        &book,
+        &profile,
+        progress::now(),
+    ))
+}
+
+pub fn serve(list: &ExerciseList, port: u16) -> Result<()> {
+    let server = Server::http(("127.0.0.1", port))
+        .map_err(|e| anyhow!("Failed to listen on port {port}: {e}"))?;
+    println!("Serving the dashboard on http://127.0.0.1:{port}/, press Ctrl+C to stop");
+
+    for request in server.incoming_requests() {
+        let response = match request.url() {
+            "/" => match page(list) {
+                Ok(html) => Response::from_string(html).with_header(
+                    Header::from_bytes("Content-Type", "text/html; charset=utf-8").unwrap(),
+                ),
+                // The CLI might be in the middle of writing a file, the next
+                // refresh will pick it up.
+                Err(e) => Response::from_string(format!("{e:?}")).with_status_code(500),
+            },
+            _ => Response::from_string("Not found").with_status_code(404),
+        };
+        // The browser going away isn't our problem.
+        let _ = request.respond(response);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verify::Outcome;
+
+    #[test]
+    fn renders_topics_chapters_and_last_results() {
+        let list = ExerciseList::checked_in();
+        let root = &list.root;
+        let book = Book::load(root).unwrap();
+        let mut state = ProgressState::fresh(&list).unwrap();
+        state.advance(&list);
+        let mut progress = Progress::default();
+        progress.exercise("intro1").record(Outcome::Passed, 1000);
+        progress
+            .exercise("intro2")
+            .record(Outcome::TestsFailed, 1030);
+
+        let html = render(&list, &state, &progress, &book, "<alice>", 1090);
+
+        assert!(html.contains(&format!(
+            "Profile <b>&lt;alice&gt;</b>: 1/{} exercises done",
+            list.exercises.len()
+        )));
+        assert!(html.contains("<tr><td>quiz2</td><td></td><td>0/1</td>"));
+        assert!(html.contains(
+            "<tr><td>06_move_semantics</td><td>§4.1-2</td><td>0/5</td>\
+             <td><progress value=\"0\" max=\"5\"></progress></td></tr>"
+        ));
+        assert!(html.contains(
+            "<tr class=\"done\"><td>1</td><td>intro1</td><td>done</td>\
+             <td><span class=\"done\">passed</span> (1m 30s ago)</td></tr>"
+        ));
+        assert!(html.contains(
+            "<tr class=\"current\"><td>2</td><td>intro2</td><td>current</td>\
+             <td><span class=\"failed\">failed its tests</span> (1m 00s ago)</td></tr>"
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+use anyhow::{Context, Result};
+use similar::TextDiff;
+use std::fs;
+
+use crate::exercise::ExerciseList;
+use crate::state::ProgressState;
+
+// The upstream tool only fills in a solution file once its exercise is done.
+// Until then the file only contains this placeholder.
+pub const PLACEHOLDER: &str = "DON'T EDIT THIS SOLUTION FILE!";
+
+// Prints a unified diff from the learner's exercise file to the reference
+// solution. Returns `false` if the solution can't be shown.
+pub fn show(list: &ExerciseList, name: &str, force: bool) -> Result<bool> {
+    let exercise = list.find(name)?;
+    let state = ProgressState::load_or_fresh(list)?;
+
+    if !force && !state.is_done(&exercise.name) {
+        println!(
+            "`{}` isn't done yet. Solve it first, or pass `--force` to spoil it anyway.",
+            exercise.name
+        );
+        return Ok(false);
+    }
+
+    let read = |path: &std::path::Path| {
+        fs::read_to_string(list.root.join(path))
+            .with_context(|| format!("Failed to read {}", path.display()))
+    };
+    let attempt = read(&exercise.path)?;
+    let solution = read(&exercise.solution)?;
+
+    if solution.contains(PLACEHOLDER) {
+        println!(
+            "There is no reference solution for `{}` in {} yet.",
+            exercise.name,
+            exercise.solution.display()
+        );
+        return Ok(false);
+    }
+
+    match diff(
+        &attempt,
+        &solution,
+        &exercise.path.display().to_string(),
+        &exercise.solution.display().to_string(),
+    ) {
+        Some(diff) => print!("{diff}"),
+        None => println!("Your solution is identical to the reference solution."),
+    }
+    Ok(true)
+}
+
+// The unified diff from the attempt to the solution, or `None` if they are
+// identical.
+fn diff(attempt: &str, solution: &str, attempt_name: &str, solution_name: &str) -> Option<String> {
+    if attempt == solution {
+        return None;
+    }
+    let diff = TextDiff::from_lines(attempt, solution);
+    Some(
+        diff.unified_diff()
+            .header(attempt_name, solution_name)
+            .to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diffs_the_attempt_against_the_solution() {
+        let attempt = "fn main() {\n    let x = 5;\n    println!(\"{x}\");\n}\n";
+        let solution = attempt.replace("let x", "let mut x");
+
+        assert_eq!(diff(attempt, attempt, "a.rs", "b.rs"), None);
+        assert_eq!(
+            diff(attempt, &solution, "a.rs", "b.rs").unwrap(),
+            "--- a.rs\n+++ b.rs\n@@ -1,4 +1,4 @@\n fn main() {\n-    let x = 5;\n\
+             +    let mut x = 5;\n     println!(\"{x}\");\n }\n"
+        );
+    }
+}
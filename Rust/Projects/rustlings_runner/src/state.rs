@@ -0,0 +1,261 @@
+/*
+Reader and writer for `.rustlings-state.txt`, the progress file shared with
+the upstream `rustlings` tool. The format is line based:
+
+    DON'T EDIT THIS FILE!
+    <blank line>
+    <current exercise>
+    <blank line>
+    <done exercise>
+    <done exercise>
+    ...
+
+The done list keeps the order the exercises were finished in, and the file has
+no trailing newline. Writing a parsed file back gives the exact same bytes.
+*/
+
+use anyhow::{bail, ensure, Context, Result};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::exercise::ExerciseList;
+
+const STATE_FILE: &str = ".rustlings-state.txt";
+const HEADER: &str = "DON'T EDIT THIS FILE!";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgressState {
+    pub current: String,
+    pub done: Vec<String>,
+}
+
+impl ProgressState {
+    pub fn path(root: &Path) -> PathBuf {
+        root.join(STATE_FILE)
+    }
+
+    // Reads the state file and checks every name against the exercise list.
+    pub fn load(list: &ExerciseList) -> Result<Self> {
+        let path = Self::path(&list.root);
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let state = Self::parse(&contents)
+            .and_then(|state| state.validate(list).map(|()| state))
+            .with_context(|| format!("Invalid progress file {}", path.display()))?;
+        Ok(state)
+    }
+
+    // The state of a learner who hasn't solved anything yet.
+    pub fn fresh(list: &ExerciseList) -> Result<Self> {
+        match list.exercises.first() {
+            Some(first) => Ok(Self {
+                current: first.name.clone(),
+                done: Vec::new(),
+            }),
+            None => bail!("There are no exercises in {}", list.root.display()),
+        }
+    }
+
+    // Like `load`, but starts from scratch when there is no state file yet.
+    pub fn load_or_fresh(list: &ExerciseList) -> Result<Self> {
+        if Self::path(&list.root).exists() {
+            Self::load(list)
+        } else {
+            Self::fresh(list)
+        }
+    }
+
+    // Validates the state before writing it, so a bad script can't corrupt
+    // the file. The new contents are renamed into place in one step.
+    pub fn write(&self, list: &ExerciseList) -> Result<()> {
+        self.validate(list)?;
+
+        let path = Self::path(&list.root);
+        let tmp = path.with_extension("txt.tmp");
+        fs::write(&tmp, self.render())
+            .with_context(|| format!("Failed to write {}", tmp.display()))?;
+        fs::rename(&tmp, &path).with_context(|| format!("Failed to replace {}", path.display()))?;
+        Ok(())
+    }
+
+    pub fn parse(contents: &str) -> Result<Self> {
+        let mut lines = contents.lines().enumerate().map(|(n, line)| (n + 1, line));
+
+        match lines.next() {
+            Some((_, HEADER)) => (),
+            _ => bail!("Line 1 must be `{HEADER}`"),
+        }
+        match lines.next() {
+            Some((_, "")) => (),
+            _ => bail!("Line 2 must be empty"),
+        }
+        let current = match lines.next() {
+            Some((_, name)) if !name.trim().is_empty() => name.to_string(),
+            _ => bail!("Line 3 must name the current exercise"),
+        };
+        match lines.next() {
+            Some((_, "")) | None => (),
+            Some(_) => bail!("Line 4 must be empty"),
+        }
+
+        let mut done = Vec::new();
+        for (n, name) in lines {
+            ensure!(!name.trim().is_empty(), "Line {n} is empty");
+            done.push(name.to_string());
+        }
+
+        Ok(Self { current, done })
+    }
+
+    pub fn render(&self) -> String {
+        let mut contents = format!("{HEADER}\n\n{}\n", self.current);
+        for name in &self.done {
+            contents.push('\n');
+            contents.push_str(name);
+        }
+        contents
+    }
+
+    pub fn validate(&self, list: &ExerciseList) -> Result<()> {
+        let known: HashSet<&str> = list.exercises.iter().map(|e| e.name.as_str()).collect();
+
+        ensure!(
+            known.contains(self.current.as_str()),
+            "The current exercise `{}` isn't a known exercise",
+            self.current
+        );
+
+        let mut seen = HashSet::new();
+        for name in &self.done {
+            ensure!(
+                known.contains(name.as_str()),
+                "The done exercise `{name}` isn't a known exercise"
+            );
+            ensure!(seen.insert(name), "`{name}` is listed as done twice");
+        }
+        Ok(())
+    }
+
+    pub fn is_done(&self, name: &str) -> bool {
+        self.done.iter().any(|done| done == name)
+    }
+
+    pub fn mark_done(&mut self, name: &str) {
+        if !self.is_done(name) {
+            self.done.push(name.to_string());
+        }
+    }
+
+    pub fn unmark_done(&mut self, name: &str) {
+        self.done.retain(|done| done != name);
+    }
+
+    // Marks the current exercise as done and moves on to the next one that
+    // isn't done yet, wrapping around to exercises that were skipped.
+    // Returns `false` when every exercise is done.
+    pub fn advance(&mut self, list: &ExerciseList) -> bool {
+        let current = self.current.clone();
+        self.mark_done(&current);
+
+        let start = list
+            .exercises
+            .iter()
+            .position(|exercise| exercise.name == current)
+            .unwrap_or(0);
+        let (before, after) = list.exercises.split_at(start);
+
+        match after.iter().chain(before).find(|e| !self.is_done(&e.name)) {
+            Some(next) => {
+                self.current = next.name.clone();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::{Exercise, Mode};
+
+    fn list(names: &[&str]) -> ExerciseList {
+        ExerciseList {
+            root: PathBuf::new(),
+            exercises: names
+                .iter()
+                .map(|name| Exercise::for_test("00_intro", name, Mode::Run))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn round_trips_byte_for_byte() {
+        let contents = "DON'T EDIT THIS FILE!\n\nstrings1\n\nintro1\nintro2";
+        let state = ProgressState::parse(contents).unwrap();
+
+        assert_eq!(state.current, "strings1");
+        assert_eq!(state.done, ["intro1", "intro2"]);
+        assert_eq!(state.render(), contents);
+    }
+
+    #[test]
+    fn round_trips_exercises_done_out_of_order() {
+        // Like the upstream tool writes it after skipping ahead.
+        let contents =
+            "DON'T EDIT THIS FILE!\n\nstrings1\n\nintro1\nintro2\nvariables1\nstructs3\nenums1";
+        let state = ProgressState::parse(contents).unwrap();
+
+        assert_eq!(state.current, "strings1");
+        assert_eq!(state.done[3..], ["structs3", "enums1"]);
+        assert_eq!(state.render(), contents);
+    }
+
+    #[test]
+    fn round_trips_without_done_exercises() {
+        let contents = "DON'T EDIT THIS FILE!\n\nintro1\n";
+        let state = ProgressState::parse(contents).unwrap();
+
+        assert!(state.done.is_empty());
+        assert_eq!(state.render(), contents);
+    }
+
+    #[test]
+    fn rejects_hand_edited_files() {
+        assert!(ProgressState::parse("intro1\n\nintro2").is_err());
+        assert!(ProgressState::parse("DON'T EDIT THIS FILE!\nintro1").is_err());
+        assert!(ProgressState::parse("DON'T EDIT THIS FILE!\n\nintro2\nintro1").is_err());
+        assert!(ProgressState::parse("DON'T EDIT THIS FILE!\n\nintro2\n\nintro1\n\n").is_err());
+    }
+
+    #[test]
+    fn validates_names_against_the_exercise_list() {
+        let list = list(&["intro1", "intro2"]);
+        let state = |current: &str, done: &[&str]| ProgressState {
+            current: current.to_string(),
+            done: done.iter().map(|name| name.to_string()).collect(),
+        };
+
+        assert!(state("intro2", &["intro1"]).validate(&list).is_ok());
+        assert!(state("intro3", &["intro1"]).validate(&list).is_err());
+        assert!(state("intro2", &["intro1_sol"]).validate(&list).is_err());
+        assert!(state("intro2", &["intro1", "intro1"])
+            .validate(&list)
+            .is_err());
+    }
+
+    #[test]
+    fn advances_to_the_next_pending_exercise() {
+        let list = list(&["intro1", "intro2", "intro3"]);
+        let mut state = ProgressState {
+            current: "intro2".to_string(),
+            done: vec!["intro3".to_string()],
+        };
+
+        assert!(state.advance(&list));
+        assert_eq!(state.current, "intro1");
+        assert!(!state.advance(&list));
+        assert_eq!(state.done, ["intro3", "intro2", "intro1"]);
+    }
+}
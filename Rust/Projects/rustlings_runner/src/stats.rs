@@ -0,0 +1,165 @@
+/*
+Learner statistics from `.rustlings-progress.toml`, summed up per topic.
+
+A topic is a directory in `exercises/`, like `06_move_semantics`. Its time is
+the sum of the time spent on each of its exercises, from the first time the
+exercise was verified or asked for a hint until it passed. Exercises that
+haven't passed yet count until now.
+*/
+
+use anyhow::Result;
+use std::collections::HashMap;
+
+use crate::exercise::ExerciseList;
+use crate::progress::{self, Progress};
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct TopicStats<'a> {
+    time: u64,
+    exercises: usize,
+    completed: usize,
+    compile_attempts: usize,
+    failed_test_runs: usize,
+    hints_revealed: usize,
+    // The exercise of the topic that took the longest, with its time.
+    slowest: Option<(&'a str, u64)>,
+}
+
+fn topics<'a>(
+    list: &'a ExerciseList,
+    progress: &Progress,
+    now: u64,
+) -> Vec<(&'a str, TopicStats<'a>)> {
+    let mut topics: HashMap<&str, TopicStats> = HashMap::new();
+    for exercise in &list.exercises {
+        let topic = topics.entry(exercise.dir.as_str()).or_default();
+        topic.exercises += 1;
+
+        let Some(stats) = progress.exercises.get(&exercise.name) else {
+            continue;
+        };
+        let time = stats.time_spent(now);
+        topic.time += time;
+        topic.completed += usize::from(stats.completed.is_some());
+        topic.compile_attempts += stats.compile_attempts;
+        topic.failed_test_runs += stats.failed_test_runs;
+        topic.hints_revealed += stats.hints_revealed;
+        if stats.first_seen.is_some() && topic.slowest.is_none_or(|(_, slowest)| time > slowest) {
+            topic.slowest = Some((&exercise.name, time));
+        }
+    }
+
+    // Topics nobody has started yet would only add empty rows.
+    let mut topics: Vec<_> = topics
+        .into_iter()
+        .filter(|(_, topic)| topic.slowest.is_some())
+        .collect();
+    topics.sort_by(|(a_name, a), (b_name, b)| b.time.cmp(&a.time).then(a_name.cmp(b_name)));
+    topics
+}
+
+pub fn format_time(seconds: u64) -> String {
+    match seconds {
+        0..60 => format!("{seconds}s"),
+        60..3600 => format!("{}m {:02}s", seconds / 60, seconds % 60),
+        _ => format!("{}h {:02}m", seconds / 3600, seconds % 3600 / 60),
+    }
+}
+
+// Prints the topics from the slowest to the fastest.
+pub fn show(list: &ExerciseList) -> Result<()> {
+    let progress = Progress::load(list)?;
+    let topics = topics(list, &progress, progress::now());
+    if topics.is_empty() {
+        println!("No statistics yet. They are recorded while you work on the exercises.");
+        return Ok(());
+    }
+
+    println!(
+        "{:<22} {:>8} {:>6} {:>8} {:>12} {:>5}  slowest exercise",
+        "topic", "time", "done", "compiles", "failed tests", "hints"
+    );
+    for (name, topic) in topics {
+        let (slowest, slowest_time) = topic.slowest.unwrap_or_default();
+        println!(
+            "{name:<22} {:>8} {:>6} {:>8} {:>12} {:>5}  {slowest} ({})",
+            format_time(topic.time),
+            format!("{}/{}", topic.completed, topic.exercises),
+            topic.compile_attempts,
+            topic.failed_test_runs,
+            topic.hints_revealed,
+            format_time(slowest_time),
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::{Exercise, Mode};
+    use crate::verify::Outcome;
+    use std::path::PathBuf;
+
+    fn list(exercises: &[(&str, &str)]) -> ExerciseList {
+        ExerciseList {
+            root: PathBuf::new(),
+            exercises: exercises
+                .iter()
+                .map(|(dir, name)| Exercise::for_test(dir, name, Mode::Test))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn sorts_topics_by_time_spent() {
+        let list = list(&[
+            ("05_vecs", "vecs1"),
+            ("05_vecs", "vecs2"),
+            ("06_move_semantics", "move_semantics1"),
+            ("06_move_semantics", "move_semantics2"),
+            ("07_structs", "structs1"),
+        ]);
+        let mut progress = Progress::default();
+        progress.exercise("vecs1").record(Outcome::Passed, 0);
+        progress.exercise("vecs1").complete(0);
+        progress.exercise("vecs2").record(Outcome::TestsFailed, 0);
+        progress.exercise("vecs2").record(Outcome::Passed, 90);
+        progress.exercise("vecs2").complete(90);
+        progress
+            .exercise("move_semantics1")
+            .record(Outcome::CompileFailed, 100);
+        progress
+            .exercise("move_semantics1")
+            .record(Outcome::Passed, 700);
+        progress.exercise("move_semantics1").complete(700);
+        progress.exercise("move_semantics2").seen(900);
+
+        let topics = topics(&list, &progress, 1000);
+
+        assert_eq!(topics.len(), 2);
+        assert_eq!(topics[0].0, "06_move_semantics");
+        assert_eq!(
+            topics[0].1,
+            TopicStats {
+                time: 700,
+                exercises: 2,
+                completed: 1,
+                compile_attempts: 2,
+                failed_test_runs: 0,
+                hints_revealed: 0,
+                slowest: Some(("move_semantics1", 600)),
+            }
+        );
+        assert_eq!(topics[1].0, "05_vecs");
+        assert_eq!(topics[1].1.time, 90);
+        assert_eq!(topics[1].1.failed_test_runs, 1);
+    }
+
+    #[test]
+    fn formats_times() {
+        assert_eq!(format_time(42), "42s");
+        assert_eq!(format_time(303), "5m 03s");
+        assert_eq!(format_time(7500), "2h 05m");
+    }
+}
@@ -0,0 +1,346 @@
+/*
+Topics and quizzes as a dependency graph, with quizzes as checkpoints.
+
+Every topic directory, like `06_move_semantics`, is a node of the graph, and
+so is every quiz. A node unlocks once all of its prerequisites are done:
+
+- A quiz requires the topics listed in its header comment:
+
+      // This is a quiz for the following sections:
+      // - Variables
+      // - Functions
+      // - If
+
+  The titles are matched against the topic directories, so "Move semantics"
+  stands for `06_move_semantics`.
+- Any exercise can add prerequisites to its node with `requires` in
+  `info.toml`, naming earlier topics or exercises. `requires = ["quiz1"]` on the
+  first exercise of a topic makes the quiz a checkpoint: that topic and every
+  node after it stay locked until the quiz is done.
+
+The exercises of a locked node can't be run or watched, and `topics` shows the
+whole map.
+*/
+
+use anyhow::{bail, ensure, Context, Result};
+use std::fs;
+
+use crate::exercise::{Exercise, ExerciseList};
+use crate::state::ProgressState;
+
+const QUIZZES_DIR: &str = "quizzes";
+const QUIZ_HEADERS: &[&str] = &[
+    "This is a quiz for the following sections:",
+    "This quiz tests:",
+];
+
+#[derive(Debug)]
+struct Node<'a> {
+    // The topic directory, or the name of the quiz.
+    name: &'a str,
+    exercises: Vec<&'a Exercise>,
+    // The names of other nodes.
+    requires: Vec<&'a str>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Status {
+    Done,
+    InProgress,
+    Open,
+    Locked,
+}
+
+impl Status {
+    // Two columns wide, the lock is a wide character.
+    fn icon(&self) -> &'static str {
+        match self {
+            Self::Done => "✓ ",
+            Self::InProgress => "->",
+            Self::Open => "  ",
+            Self::Locked => "🔒",
+        }
+    }
+}
+
+pub struct Graph<'a> {
+    nodes: Vec<Node<'a>>,
+    // The quizzes that are required with `requires`, each with the index of
+    // the first node that requires it. They lock every node from there on.
+    checkpoints: Vec<(usize, &'a str)>,
+}
+
+// The topic directory of the exercise, or its name for a quiz.
+pub fn node_name(exercise: &Exercise) -> &str {
+    if exercise.dir == QUIZZES_DIR {
+        &exercise.name
+    } else {
+        &exercise.dir
+    }
+}
+
+// The section titles listed in a quiz's header comment.
+fn quiz_topics(source: &str) -> Vec<&str> {
+    let mut lines = source.lines().map(str::trim);
+    if !lines.any(|line| {
+        QUIZ_HEADERS
+            .iter()
+            .any(|header| line == format!("// {header}"))
+    }) {
+        return Vec::new();
+    }
+    lines
+        .map_while(|line| line.strip_prefix("// - "))
+        .map(str::trim)
+        .collect()
+}
+
+// The topic directory for a section title, like `03_if` for "If".
+fn resolve_topic<'a>(list: &'a ExerciseList, title: &str) -> Option<&'a str> {
+    let wanted = title.to_lowercase().replace(' ', "_");
+    list.exercises
+        .iter()
+        .map(|exercise| exercise.dir.as_str())
+        .find(|dir| {
+            dir.split_once('_')
+                .is_some_and(|(_, topic)| topic == wanted)
+        })
+}
+
+impl<'a> Graph<'a> {
+    pub fn load(list: &'a ExerciseList) -> Result<Self> {
+        let mut nodes: Vec<Node> = Vec::new();
+        for exercise in &list.exercises {
+            let name = node_name(exercise);
+            if !nodes.iter().any(|node| node.name == name) {
+                nodes.push(Node {
+                    name,
+                    exercises: Vec::new(),
+                    requires: Vec::new(),
+                });
+            }
+
+            let mut requires = Vec::new();
+            if exercise.dir == QUIZZES_DIR {
+                // The header is the same in both copies, but the learner might
+                // have deleted it from theirs.
+                let pristine = list.root.join(&exercise.pristine);
+                let path = if pristine.exists() {
+                    pristine
+                } else {
+                    list.root.join(&exercise.path)
+                };
+                let source = fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?;
+                for title in quiz_topics(&source) {
+                    let topic = resolve_topic(list, title).with_context(|| {
+                        format!(
+                            "`{}` is a quiz for \"{title}\", but there is no such topic",
+                            exercise.name
+                        )
+                    })?;
+                    ensure!(
+                        nodes.iter().any(|node| node.name == topic),
+                        "`{}` is a quiz for `{topic}`, which comes after it",
+                        exercise.name
+                    );
+                    requires.push(topic);
+                }
+            }
+            for required in &exercise.requires {
+                // `info.toml` only allows earlier topics and exercises.
+                let topic = match list.find(required) {
+                    Ok(required) => node_name(required),
+                    Err(_) => required.as_str(),
+                };
+                requires.push(topic);
+            }
+
+            let node = nodes.iter_mut().find(|node| node.name == name).unwrap();
+            for topic in requires {
+                if topic == name {
+                    bail!("`{}` requires its own topic `{name}`", exercise.name);
+                }
+                if !node.requires.contains(&topic) {
+                    node.requires.push(topic);
+                }
+            }
+            node.exercises.push(exercise);
+        }
+
+        let mut checkpoints: Vec<(usize, &str)> = Vec::new();
+        for (n, node) in nodes.iter().enumerate() {
+            for &required in &node.requires {
+                let is_quiz = nodes
+                    .iter()
+                    .any(|other| other.name == required && other.exercises[0].dir == QUIZZES_DIR);
+                if is_quiz && !checkpoints.iter().any(|&(_, quiz)| quiz == required) {
+                    checkpoints.push((n, required));
+                }
+            }
+        }
+        Ok(Self { nodes, checkpoints })
+    }
+
+    fn node(&self, name: &str) -> &Node<'a> {
+        self.nodes.iter().find(|node| node.name == name).unwrap()
+    }
+
+    fn is_done(&self, name: &str, state: &ProgressState) -> bool {
+        self.node(name)
+            .exercises
+            .iter()
+            .all(|exercise| state.is_done(&exercise.name))
+    }
+
+    // The prerequisites of the exercise's topic that aren't done yet, with the
+    // checkpoints before it.
+    pub fn missing(&self, exercise: &Exercise, state: &ProgressState) -> Vec<&'a str> {
+        let name = node_name(exercise);
+        let index = self
+            .nodes
+            .iter()
+            .position(|node| node.name == name)
+            .unwrap();
+        let checkpoints = self
+            .checkpoints
+            .iter()
+            .filter(|&&(first, _)| first <= index)
+            .map(|&(_, quiz)| quiz);
+
+        let mut missing = Vec::new();
+        for required in checkpoints.chain(self.nodes[index].requires.iter().copied()) {
+            if !missing.contains(&required) && !self.is_done(required, state) {
+                missing.push(required);
+            }
+        }
+        missing
+    }
+
+    fn status(&self, node: &Node, state: &ProgressState) -> Status {
+        let done = node
+            .exercises
+            .iter()
+            .filter(|exercise| state.is_done(&exercise.name))
+            .count();
+        if done == node.exercises.len() {
+            Status::Done
+        } else if !self.missing(node.exercises[0], state).is_empty() {
+            Status::Locked
+        } else if done > 0
+            || node
+                .exercises
+                .iter()
+                .any(|exercise| exercise.name == state.current)
+        {
+            Status::InProgress
+        } else {
+            Status::Open
+        }
+    }
+}
+
+// Fails with the missing prerequisites when the exercise is locked.
+pub fn ensure_unlocked(list: &ExerciseList, exercise: &Exercise) -> Result<()> {
+    let graph = Graph::load(list)?;
+    let state = ProgressState::load_or_fresh(list)?;
+    let missing = graph.missing(exercise, &state);
+    ensure!(
+        missing.is_empty(),
+        "`{}` is locked until you are done with {}",
+        exercise.name,
+        missing.join(", ")
+    );
+    Ok(())
+}
+
+// Prints every topic and quiz in order with its progress and prerequisites.
+pub fn show(list: &ExerciseList) -> Result<()> {
+    let graph = Graph::load(list)?;
+    let state = ProgressState::load_or_fresh(list)?;
+
+    for node in &graph.nodes {
+        let status = graph.status(node, &state);
+        let done = node
+            .exercises
+            .iter()
+            .filter(|exercise| state.is_done(&exercise.name))
+            .count();
+        let mut line = format!(
+            "{} {:<20} {:>2}/{:<2}",
+            status.icon(),
+            node.name,
+            done,
+            node.exercises.len()
+        );
+        if !node.requires.is_empty() {
+            line.push_str(&format!("  after {}", node.requires.join(", ")));
+        }
+        if status == Status::Locked {
+            let missing = graph.missing(node.exercises[0], &state);
+            line.push_str(&format!(" (waiting for {})", missing.join(", ")));
+        }
+        println!("{}", line.trim_end());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_the_sections_of_quiz_headers() {
+        let quiz =
+            "// This quiz tests:\n// - Generics\n// - Move semantics\n//\n// - Not a topic\n";
+
+        assert_eq!(quiz_topics(quiz), ["Generics", "Move semantics"]);
+        assert_eq!(
+            quiz_topics("// - Uppercase the string\n"),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn quizzes_require_their_sections_and_gate_the_topics_after_them() {
+        let list = ExerciseList::checked_in();
+        let graph = Graph::load(&list).unwrap();
+
+        assert_eq!(
+            graph.node("quiz2").requires,
+            [
+                "09_strings",
+                "05_vecs",
+                "06_move_semantics",
+                "10_modules",
+                "08_enums"
+            ]
+        );
+        assert_eq!(graph.node("12_options").requires, ["quiz2"]);
+
+        let mut state = ProgressState::fresh(&list).unwrap();
+        for exercise in &list.exercises {
+            if exercise.dir != "09_strings" && exercise.dir != QUIZZES_DIR {
+                state.mark_done(&exercise.name);
+            }
+        }
+        state.mark_done("quiz1");
+        let missing = |name: &str| graph.missing(list.find(name).unwrap(), &state);
+        assert_eq!(missing("quiz2"), ["09_strings"]);
+        assert_eq!(missing("options1"), ["quiz2"]);
+        assert_eq!(graph.status(graph.node("quiz2"), &state), Status::Locked);
+        // Checkpoints lock every later topic, not just the one requiring them.
+        assert_eq!(missing("errors1"), ["quiz2"]);
+        assert_eq!(missing("quiz3"), ["quiz2"]);
+        assert_eq!(missing("lifetimes1"), ["quiz2", "quiz3"]);
+        assert!(missing("strings1").is_empty());
+
+        for exercise in list.exercises.iter().filter(|e| e.dir == "09_strings") {
+            state.mark_done(&exercise.name);
+        }
+        assert!(graph
+            .missing(list.find("quiz2").unwrap(), &state)
+            .is_empty());
+        assert_eq!(graph.status(graph.node("quiz2"), &state), Status::Open);
+    }
+}
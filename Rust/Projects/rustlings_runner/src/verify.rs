@@ -0,0 +1,235 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use crate::book::Book;
+use crate::diagnostic::{self, Build, Diagnostic};
+use crate::exercise::{Exercise, Mode};
+use crate::integrity;
+use crate::process::{self, Run};
+
+// How long running an exercise or its tests may take, unless `info.toml`
+// says otherwise for the exercise.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Passed,
+    CompileFailed,
+    ClippyFailed,
+    RunFailed,
+    TimedOut,
+    ChannelHang,
+    WrongOutput,
+    TestsFailed,
+    // Code that is marked as protected was changed, see `integrity`.
+    Tampered,
+}
+
+impl Outcome {
+    pub fn passed(self) -> bool {
+        self == Outcome::Passed
+    }
+
+    pub fn describe(self) -> &'static str {
+        match self {
+            Outcome::Passed => "passed",
+            Outcome::CompileFailed => "failed to compile",
+            Outcome::ClippyFailed => "has Clippy warnings",
+            Outcome::RunFailed => "failed to run",
+            Outcome::TimedOut => "timed out",
+            Outcome::ChannelHang => "timed out (likely a channel sender was never dropped)",
+            Outcome::WrongOutput => "printed the wrong output",
+            Outcome::TestsFailed => "failed its tests",
+            Outcome::Tampered => "changed code that must not be changed",
+        }
+    }
+}
+
+pub struct Report {
+    pub outcome: Outcome,
+    // Everything cargo and the exercise printed, in the order of the steps.
+    pub output: String,
+}
+
+// Prefer the cargo that launched us (`cargo run -p rustlings_runner`) so the
+// exercises are built with the same toolchain.
+pub fn cargo() -> Command {
+    Command::new(std::env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo")))
+}
+
+// Runs a cargo command on the exercise's bin with JSON messages and collects
+// its diagnostics. Cargo's own errors, like a broken `Cargo.toml`, are
+// appended to the output as they are.
+fn cargo_step(
+    root: &Path,
+    exercise: &Exercise,
+    args: &[&str],
+    rustc_args: &[&str],
+    diagnostics: &mut Vec<Diagnostic>,
+    output: &mut String,
+) -> Result<(bool, Build)> {
+    let mut cargo = cargo();
+    cargo
+        .args(args)
+        .args(["--quiet", "--bin", &exercise.name, "--message-format=json"])
+        .current_dir(root);
+    if !rustc_args.is_empty() {
+        cargo.arg("--").args(rustc_args);
+    }
+    let result = cargo
+        .output()
+        .with_context(|| format!("Failed to run {cargo:?}"))?;
+
+    let mut build = Build::parse(&String::from_utf8_lossy(&result.stdout));
+    let found = build.diagnostics.remove(&exercise.name).unwrap_or_default();
+    if found.is_empty() && !result.status.success() {
+        output.push_str(&String::from_utf8_lossy(&result.stderr));
+    }
+    // The test build repeats the warnings of the normal build.
+    for diagnostic in &found {
+        if !diagnostics.contains(diagnostic) {
+            diagnostics.push(diagnostic.clone());
+        }
+    }
+    build.diagnostics.insert(exercise.name.clone(), found);
+    Ok((result.status.success(), build))
+}
+
+// Runs Clippy on the single bin with warnings denied and collects every lint
+// with its span. The exercise passes this step only if Clippy reports nothing.
+pub fn clippy_step(
+    root: &Path,
+    exercise: &Exercise,
+    diagnostics: &mut Vec<Diagnostic>,
+    output: &mut String,
+) -> Result<bool> {
+    let (success, build) = cargo_step(
+        root,
+        exercise,
+        &["clippy"],
+        &["-D", "warnings"],
+        diagnostics,
+        output,
+    )?;
+    Ok(success && build.diagnostics[&exercise.name].is_empty())
+}
+
+// Runs the binary that `cargo build` just produced, wherever the target
+// directory is configured. Going through `cargo run` would print the compiler
+// warnings a second time. The binary's stdout is appended to the output too.
+fn run_step(root: &Path, binary: &Path, timeout: Duration, output: &mut String) -> Result<Run> {
+    let run = process::run(Command::new(binary).current_dir(root), timeout)?;
+
+    output.push_str(&run.stderr);
+    output.push_str(&run.stdout);
+    Ok(run)
+}
+
+// Compiles the tests with `cargo test --no-run` so that only running them
+// counts against the timeout. Returns the test binary, or `None` if the tests
+// don't compile.
+fn build_tests_step(
+    root: &Path,
+    exercise: &Exercise,
+    diagnostics: &mut Vec<Diagnostic>,
+    output: &mut String,
+) -> Result<Option<PathBuf>> {
+    let args = ["test", "--no-run"];
+    let (_, mut build) = cargo_step(root, exercise, &args, &[], diagnostics, output)?;
+    Ok(build.test_executables.remove(&exercise.name))
+}
+
+// A hanging exercise that uses channels is most likely waiting on a receiver
+// whose sender is still alive, as in `threads3`.
+pub fn timed_out(root: &Path, exercise: &Exercise) -> Outcome {
+    match std::fs::read_to_string(root.join(&exercise.path)) {
+        Ok(source) if source.contains("mpsc") => Outcome::ChannelHang,
+        _ => Outcome::TimedOut,
+    }
+}
+
+// An exercise passes when it compiles, its `main` exits successfully and its
+// tests pass in test mode, and neither the tests nor the code marked "Don't
+// change" were edited. Clippy-strict exercises also have to be free of
+// Clippy warnings, and exercises with an expected output have to print it.
+// Running `main` and running the tests each have to finish before the
+// exercise's timeout.
+//
+// The compiler diagnostics of all steps come first in the output, rendered
+// together under the exercise's name.
+pub fn verify(root: &Path, exercise: &Exercise) -> Result<Report> {
+    let mut diagnostics = Vec::new();
+    let mut output = String::new();
+    let outcome = outcome(root, exercise, &mut diagnostics, &mut output)?;
+    // A killed exercise can stop in the middle of a line.
+    if !output.is_empty() && !output.ends_with('\n') {
+        output.push('\n');
+    }
+    if !diagnostics.is_empty() {
+        let book = Book::load(root).unwrap_or_default();
+        output.insert_str(0, &diagnostic::render_group(exercise, &diagnostics, &book));
+    }
+    Ok(Report { outcome, output })
+}
+
+fn outcome(
+    root: &Path,
+    exercise: &Exercise,
+    diagnostics: &mut Vec<Diagnostic>,
+    output: &mut String,
+) -> Result<Outcome> {
+    let timeout = exercise.timeout.unwrap_or(DEFAULT_TIMEOUT);
+
+    let tampered = integrity::check(root, exercise)?;
+    if !tampered.is_empty() {
+        for change in tampered {
+            output.push_str(&change);
+            output.push('\n');
+        }
+        output.push_str("Undo these changes, or start over with `reset`.\n");
+        return Ok(Outcome::Tampered);
+    }
+
+    let (built, mut build) = cargo_step(root, exercise, &["build"], &[], diagnostics, output)?;
+    let Some(binary) = build.executables.remove(&exercise.name).filter(|_| built) else {
+        return Ok(Outcome::CompileFailed);
+    };
+    if exercise.strict_clippy && !clippy_step(root, exercise, diagnostics, output)? {
+        return Ok(Outcome::ClippyFailed);
+    }
+
+    let run = run_step(root, &binary, timeout, output)?;
+    if run.timed_out() {
+        return Ok(timed_out(root, exercise));
+    }
+    if !run.success() {
+        return Ok(Outcome::RunFailed);
+    }
+    if let Some(expected) = &exercise.expected_output {
+        if let Some(mismatch) = expected.check(&run.stdout)? {
+            output.push_str(&mismatch);
+            return Ok(Outcome::WrongOutput);
+        }
+    }
+
+    if exercise.mode == Mode::Test {
+        let Some(tests) = build_tests_step(root, exercise, diagnostics, output)? else {
+            return Ok(Outcome::TestsFailed);
+        };
+        let run = process::run(Command::new(tests).current_dir(root), timeout)?;
+        output.push_str(&run.stderr);
+        output.push_str(&run.stdout);
+        if run.timed_out() {
+            return Ok(timed_out(root, exercise));
+        }
+        if !run.success() {
+            return Ok(Outcome::TestsFailed);
+        }
+    }
+    Ok(Outcome::Passed)
+}
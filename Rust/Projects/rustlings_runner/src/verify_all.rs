@@ -0,0 +1,387 @@
+/*
+Scores a whole checkout in one pass, for grading.
+
+Verifying the exercises one by one runs cargo twice per exercise, and parallel
+cargo runs would only wait on each other's lock on the build directory. So
+instead:
+
+1. One `cargo build --keep-going --bins --tests` compiles every bin and its
+   tests. Cargo parallelizes that itself and keeps going past the exercises
+   that don't compile.
+2. The clippy-strict exercises that compiled are linted.
+3. A pool of workers runs the binaries and the test binaries, each with a
+   deadline. The `timeout` of an exercise in `info.toml` takes precedence over
+   the one given on the command line.
+
+Unlike `verify`, nothing is written to `.rustlings-state.txt`. The result is a
+JSON or JUnit XML report with the compile errors, the failing test names and
+the durations of every exercise.
+*/
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::Path;
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::book::Book;
+use crate::diagnostic::{self, Build};
+use crate::exercise::{Exercise, ExerciseList, Mode};
+use crate::integrity;
+use crate::process;
+use crate::verify::{self, Outcome};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    Json,
+    Junit,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExerciseResult {
+    pub name: String,
+    pub dir: String,
+    pub outcome: Outcome,
+    // Time spent running the exercise and its tests. Compiling happens for
+    // all exercises at once, see `Summary::build_ms`.
+    pub duration_ms: u128,
+    pub compile_errors: Vec<String>,
+    pub failing_tests: Vec<String>,
+    // What the failing step printed.
+    pub output: String,
+}
+
+impl ExerciseResult {
+    fn new(exercise: &Exercise, outcome: Outcome) -> Self {
+        Self {
+            name: exercise.name.clone(),
+            dir: exercise.dir.clone(),
+            outcome,
+            duration_ms: 0,
+            compile_errors: Vec::new(),
+            failing_tests: Vec::new(),
+            output: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Summary {
+    pub passed: usize,
+    pub total: usize,
+    pub build_ms: u128,
+    pub duration_ms: u128,
+    pub exercises: Vec<ExerciseResult>,
+}
+
+pub struct Options {
+    pub jobs: usize,
+    pub timeout: Duration,
+}
+
+fn build(root: &Path) -> Result<Build> {
+    let mut cargo = verify::cargo();
+    cargo
+        .args(["build", "--keep-going", "--bins", "--tests"])
+        .arg("--message-format=json")
+        .current_dir(root);
+    let result = cargo
+        .output()
+        .with_context(|| format!("Failed to run {cargo:?}"))?;
+    Ok(Build::parse(&String::from_utf8_lossy(&result.stdout)))
+}
+
+// The names of the failing tests in libtest's output, e.g. `tests::foo` from
+// `test tests::foo ... FAILED`.
+fn failing_tests(stdout: &str) -> Vec<String> {
+    stdout
+        .lines()
+        .filter_map(|line| line.strip_prefix("test ")?.strip_suffix(" ... FAILED"))
+        .map(str::to_string)
+        .collect()
+}
+
+fn check(
+    root: &Path,
+    exercise: &Exercise,
+    build: &Build,
+    book: &Book,
+    lints: Option<&String>,
+    timeout: Duration,
+) -> Result<ExerciseResult> {
+    let timeout = exercise.timeout.unwrap_or(timeout);
+    let tampered = integrity::check(root, exercise)?;
+    if !tampered.is_empty() {
+        let mut result = ExerciseResult::new(exercise, Outcome::Tampered);
+        result.output = tampered.join("\n");
+        return Ok(result);
+    }
+    let compile_errors = || -> Vec<String> {
+        build
+            .diagnostics
+            .get(&exercise.name)
+            .into_iter()
+            .flatten()
+            .filter(|diagnostic| diagnostic.level == "error")
+            .map(|diagnostic| diagnostic.render(book))
+            .collect()
+    };
+
+    let Some(binary) = build.executables.get(&exercise.name) else {
+        let mut result = ExerciseResult::new(exercise, Outcome::CompileFailed);
+        result.compile_errors = compile_errors();
+        return Ok(result);
+    };
+    if let Some(lints) = lints {
+        let mut result = ExerciseResult::new(exercise, Outcome::ClippyFailed);
+        result.output = lints.clone();
+        return Ok(result);
+    }
+
+    let run = process::run(Command::new(binary).current_dir(root), timeout)?;
+    let mut duration = run.duration;
+    let finish = |mut result: ExerciseResult, duration: Duration| {
+        result.duration_ms = duration.as_millis();
+        Ok(result)
+    };
+
+    let mut mismatch = None;
+    let failed = if run.timed_out() {
+        Some(verify::timed_out(root, exercise))
+    } else if !run.success() {
+        Some(Outcome::RunFailed)
+    } else if let Some(expected) = &exercise.expected_output {
+        mismatch = expected.check(&run.stdout)?;
+        mismatch.as_ref().map(|_| Outcome::WrongOutput)
+    } else {
+        None
+    };
+    if let Some(outcome) = failed {
+        let mut result = ExerciseResult::new(exercise, outcome);
+        result.output = format!(
+            "{}{}{}",
+            run.stderr,
+            run.stdout,
+            mismatch.unwrap_or_default()
+        );
+        return finish(result, duration);
+    }
+
+    if exercise.mode == Mode::Test {
+        let Some(tests) = build.test_executables.get(&exercise.name) else {
+            let mut result = ExerciseResult::new(exercise, Outcome::TestsFailed);
+            result.compile_errors = compile_errors();
+            return finish(result, duration);
+        };
+        let run = process::run(Command::new(tests).current_dir(root), timeout)?;
+        duration += run.duration;
+
+        if !run.success() {
+            let outcome = if run.timed_out() {
+                verify::timed_out(root, exercise)
+            } else {
+                Outcome::TestsFailed
+            };
+            let mut result = ExerciseResult::new(exercise, outcome);
+            result.failing_tests = failing_tests(&run.stdout);
+            result.output = format!("{}{}", run.stderr, run.stdout);
+            return finish(result, duration);
+        }
+    }
+
+    finish(ExerciseResult::new(exercise, Outcome::Passed), duration)
+}
+
+pub fn verify_all(list: &ExerciseList, options: &Options) -> Result<Summary> {
+    let start = Instant::now();
+    eprintln!("Compiling {} exercises…", list.exercises.len());
+    let build = build(&list.root)?;
+    let build_ms = start.elapsed().as_millis();
+    let book = Book::load(&list.root).unwrap_or_default();
+
+    let mut lints = HashMap::new();
+    for exercise in &list.exercises {
+        if exercise.strict_clippy && build.executables.contains_key(&exercise.name) {
+            let mut diagnostics = Vec::new();
+            let mut output = String::new();
+            if !verify::clippy_step(&list.root, exercise, &mut diagnostics, &mut output)? {
+                output.insert_str(0, &diagnostic::render_group(exercise, &diagnostics, &book));
+                lints.insert(exercise.name.as_str(), output);
+            }
+        }
+    }
+
+    // Each worker takes the next exercise that nobody has taken yet.
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..options.jobs.max(1))
+            .map(|_| {
+                scope.spawn(|| -> Result<()> {
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(exercise) = list.exercises.get(index) else {
+                            return Ok(());
+                        };
+                        let lints = lints.get(exercise.name.as_str());
+                        let result =
+                            check(&list.root, exercise, &build, &book, lints, options.timeout)?;
+                        match result.outcome {
+                            Outcome::Passed => eprintln!("✓ {}", result.name),
+                            outcome => eprintln!("✗ {} {}", result.name, outcome.describe()),
+                        }
+                        results.lock().unwrap().push((index, result));
+                    }
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .try_for_each(|worker| worker.join().expect("A worker panicked"))
+    })?;
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(index, _)| *index);
+    let exercises: Vec<ExerciseResult> = results.into_iter().map(|(_, result)| result).collect();
+
+    Ok(Summary {
+        passed: exercises.iter().filter(|r| r.outcome.passed()).count(),
+        total: exercises.len(),
+        build_ms,
+        duration_ms: start.elapsed().as_millis(),
+        exercises,
+    })
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters other than whitespace aren't allowed in XML.
+            c if c.is_control() && !matches!(c, '\n' | '\r' | '\t') => (),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn seconds(ms: u128) -> String {
+    format!("{:.3}", ms as f64 / 1000.0)
+}
+
+// One test suite with one test case per exercise, grouped by topic through the
+// class name.
+pub fn junit(summary: &Summary) -> String {
+    let failures = summary.total - summary.passed;
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"rustlings\" tests=\"{}\" failures=\"{failures}\" time=\"{}\">",
+        summary.total,
+        seconds(summary.duration_ms)
+    );
+    let _ = writeln!(
+        xml,
+        "  <testsuite name=\"rustlings\" tests=\"{}\" failures=\"{failures}\" time=\"{}\">",
+        summary.total,
+        seconds(summary.duration_ms)
+    );
+    for result in &summary.exercises {
+        let _ = write!(
+            xml,
+            "    <testcase name=\"{}\" classname=\"{}\" time=\"{}\"",
+            escape_xml(&result.name),
+            escape_xml(&result.dir),
+            seconds(result.duration_ms)
+        );
+        if result.outcome.passed() {
+            xml.push_str("/>\n");
+            continue;
+        }
+
+        let mut details = result.compile_errors.concat();
+        for test in &result.failing_tests {
+            let _ = writeln!(details, "failing test: {test}");
+        }
+        details.push_str(&result.output);
+        let _ = writeln!(
+            xml,
+            ">\n      <failure type=\"{:?}\" message=\"{}\">{}</failure>\n    </testcase>",
+            result.outcome,
+            escape_xml(result.outcome.describe()),
+            escape_xml(&details)
+        );
+    }
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+pub fn render(summary: &Summary, format: Format) -> Result<String> {
+    match format {
+        Format::Json => {
+            serde_json::to_string_pretty(summary).context("Failed to serialize the report")
+        }
+        Format::Junit => Ok(junit(summary)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_failing_tests() {
+        let stdout = "\nrunning 3 tests\ntest tests::a ... ok\ntest tests::b ... FAILED\n\
+                      test tests::c ... FAILED\n\nfailures:\n";
+
+        assert_eq!(failing_tests(stdout), ["tests::b", "tests::c"]);
+    }
+
+    #[test]
+    fn writes_junit_xml() {
+        let result = |name: &str, outcome| ExerciseResult {
+            name: name.to_string(),
+            dir: "03_if".to_string(),
+            outcome,
+            duration_ms: 1500,
+            compile_errors: Vec::new(),
+            failing_tests: Vec::new(),
+            output: String::new(),
+        };
+        let mut failed = result("if2", Outcome::TestsFailed);
+        failed.failing_tests.push("tests::bar_for_fuzz".to_string());
+        failed.output = "assertion `left == right` failed: <1>".to_string();
+        let summary = Summary {
+            passed: 1,
+            total: 2,
+            build_ms: 0,
+            duration_ms: 3000,
+            exercises: vec![result("if1", Outcome::Passed), failed],
+        };
+
+        assert_eq!(
+            junit(&summary),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="rustlings" tests="2" failures="1" time="3.000">
+  <testsuite name="rustlings" tests="2" failures="1" time="3.000">
+    <testcase name="if1" classname="03_if" time="1.500"/>
+    <testcase name="if2" classname="03_if" time="1.500">
+      <failure type="TestsFailed" message="failed its tests">failing test: tests::bar_for_fuzz
+assertion `left == right` failed: &lt;1&gt;</failure>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+        );
+    }
+}
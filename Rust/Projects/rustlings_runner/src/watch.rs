@@ -0,0 +1,152 @@
+/*
+Watch mode: verifies the current exercise from `.rustlings-state.txt` every
+time a file under `exercises/` is saved. Once it passes, entering `n` marks it
+as done and moves on to the next one. Entering `h` reveals the next hint.
+*/
+
+use anyhow::{Context, Result};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use crate::exercise::{Exercise, ExerciseList};
+use crate::hint;
+use crate::progress::Progress;
+use crate::state::ProgressState;
+use crate::topics;
+use crate::verify;
+
+// Editors often write a file in several steps, so wait for the burst of
+// events to settle before compiling.
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+pub enum Event {
+    FileChanged(PathBuf),
+    Input(String),
+}
+
+// Sends every file saved under `dir` and every line entered on stdin. Files
+// are only watched as long as the returned watcher is alive.
+pub fn events(dir: &Path) -> Result<(RecommendedWatcher, Receiver<Event>)> {
+    let (tx, rx) = mpsc::channel();
+
+    let file_tx = tx.clone();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        if let Ok(event) = event {
+            if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                for path in event.paths {
+                    let _ = file_tx.send(Event::FileChanged(path));
+                }
+            }
+        }
+    })
+    .context("Failed to start the file watcher")?;
+    watcher
+        .watch(dir, RecursiveMode::Recursive)
+        .with_context(|| format!("Failed to watch {}", dir.display()))?;
+
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if tx.send(Event::Input(line.trim().to_string())).is_err() {
+                break;
+            }
+        }
+    });
+    Ok((watcher, rx))
+}
+
+pub fn watch(list: &ExerciseList) -> Result<()> {
+    let (_watcher, rx) = events(&list.root.join("exercises"))?;
+
+    let mut state = ProgressState::load_or_fresh(list)?;
+    let mut passed = check(list, list.find(&state.current)?)?;
+    let mut queued = None;
+
+    loop {
+        let event = match queued.take() {
+            Some(event) => event,
+            None => match rx.recv() {
+                Ok(event) => event,
+                Err(_) => break,
+            },
+        };
+        match event {
+            Event::FileChanged(path) => {
+                let exercise = list.find(&state.current)?;
+                if is_exercise_file(&list.root, exercise, &path) {
+                    queued = drain(&rx);
+                    passed = check(list, exercise)?;
+                }
+            }
+            Event::Input(input) => match input.as_str() {
+                "n" if passed => {
+                    Progress::complete(list, &state.current)?;
+                    if !state.advance(list) {
+                        state.write(list)?;
+                        println!("🎉 All exercises are done!");
+                        break;
+                    }
+                    state.write(list)?;
+                    passed = check(list, list.find(&state.current)?)?;
+                }
+                "n" => println!("`{}` doesn't pass yet. Keep going!", state.current),
+                "h" => hint::reveal(list, &state.current)?,
+                "q" => break,
+                _ => println!("Enter `n` for the next exercise, `h` for a hint or `q` to quit"),
+            },
+        }
+    }
+
+    Ok(())
+}
+
+// Skips the other file events of the same save. Input that arrives meanwhile
+// is returned so it isn't lost.
+pub fn drain(rx: &Receiver<Event>) -> Option<Event> {
+    loop {
+        match rx.recv_timeout(DEBOUNCE) {
+            Ok(Event::FileChanged(_)) => continue,
+            Ok(input) => return Some(input),
+            Err(_) => return None,
+        }
+    }
+}
+
+fn is_exercise_file(root: &Path, exercise: &Exercise, changed: &Path) -> bool {
+    match (
+        root.join(&exercise.path).canonicalize(),
+        changed.canonicalize(),
+    ) {
+        (Ok(exercise_path), Ok(changed)) => exercise_path == changed,
+        _ => false,
+    }
+}
+
+fn check(list: &ExerciseList, exercise: &Exercise) -> Result<bool> {
+    // Clear the terminal so only the latest attempt is on screen.
+    print!("\x1b[2J\x1b[H");
+
+    if let Err(locked) = topics::ensure_unlocked(list, exercise) {
+        println!("{locked}");
+        println!("Enter `q` and run `verify` to go back to the first exercise that isn't done.");
+        return Ok(false);
+    }
+    println!("Compiling `{}`...\n", exercise.path.display());
+
+    let report = verify::verify(&list.root, exercise)?;
+    Progress::record(list, &exercise.name, report.outcome)?;
+    print!("{}", report.output);
+    println!("\n`{}` {}", exercise.name, report.outcome.describe());
+
+    if report.outcome.passed() {
+        println!("Enter `n` to move on to the next exercise, or keep experimenting.");
+    } else {
+        println!("Waiting for you to save `{}`...", exercise.path.display());
+        println!("Enter `h` if you are stuck.");
+    }
+    Ok(report.outcome.passed())
+}